@@ -0,0 +1,134 @@
+//! Generates the C header from the constants and extern "C" functions in
+//! src/ffi.rs, into OUT_DIR so the build never writes to the source tree.
+//! include/algo.h is a committed copy, which tests/ffi.rs checks is up to
+//! date.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const GUARD: &str = "ALGO_H";
+
+
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    let dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let source = fs::read_to_string(Path::new(&dir).join("src/ffi.rs")).unwrap();
+    let header = make_header(&source);
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("algo.h");
+    fs::write(path, header).unwrap();
+}
+
+
+
+// Turn the module comment into the header's comment, each pub const into a
+// #define and each extern "C" fn into a prototype, keeping the comments
+// written just above them.
+fn make_header(source: &str) -> String {
+    let mut header = String::from("// Generated from src/ffi.rs by build.rs. Do not edit.\n//\n");
+    let mut body = String::new();
+    let mut comments: Vec<&str> = vec![];
+    let mut lines = source.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if let Some(text) = line.strip_prefix("//!") {
+            header.push_str(&format!("//{text}\n"));
+        } else if line.starts_with("//") {
+            comments.push(line);
+        } else if line.starts_with("#[") {
+            continue;
+        } else if let Some(constant) = line.strip_prefix("pub const ") {
+            let (name, rest) = constant.split_once(':').unwrap();
+            let mut value = rest.split_once('=').unwrap().1.trim().trim_end_matches(';').to_string();
+            if value.starts_with('-') {
+                value = format!("({value})");
+            }
+            push_item(&mut body, &comments, &format!("#define {name} {value}"));
+            comments.clear();
+        } else if line.starts_with("pub extern \"C\" fn") || line.starts_with("pub unsafe extern \"C\" fn") {
+            // The signature runs up to the opening brace of the body.
+            let mut signature = line.to_string();
+            while !signature.contains('{') {
+                signature.push(' ');
+                signature.push_str(lines.next().unwrap().trim());
+            }
+            push_item(&mut body, &comments, &prototype(&signature));
+            comments.clear();
+        } else {
+            comments.clear();
+        }
+    }
+
+    header.push_str(&format!("\n#ifndef {GUARD}\n#define {GUARD}\n\n"));
+    header.push_str("#include <stdbool.h>\n#include <stddef.h>\n#include <stdint.h>\n\n");
+    header.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n");
+    header.push_str(&body);
+    header.push_str("\n#ifdef __cplusplus\n}\n#endif\n\n");
+    header.push_str(&format!("#endif // {GUARD}\n"));
+    return header;
+}
+
+
+
+// Add a declaration with its comments. Prototypes and commented items get a
+// blank line before them; uncommented #defines run on from the one above.
+fn push_item(body: &mut String, comments: &Vec<&str>, declaration: &str) {
+    if !comments.is_empty() || !declaration.starts_with("#define") || body.is_empty() {
+        body.push('\n');
+    }
+    for comment in comments.iter() {
+        body.push_str(comment);
+        body.push('\n');
+    }
+    body.push_str(declaration);
+    body.push('\n');
+}
+
+
+
+// "pub unsafe extern "C" fn name(a: i32, b: *mut i64) -> i32 {"
+// becomes "int32_t name(int32_t a, int64_t* b);".
+fn prototype(signature: &str) -> String {
+    let after_fn = signature.split_once(" fn ").unwrap().1;
+    let (name, rest) = after_fn.split_once('(').unwrap();
+    let close = rest.rfind(')').unwrap();
+    let (params, tail) = (&rest[..close], &rest[close + 1..]);
+    let return_type = match tail.split_once("->") {
+        Some((_, ty)) => c_type(ty.trim_end_matches('{').trim()),
+        None => String::from("void"),
+    };
+
+    let mut c_params: Vec<String> = vec![];
+    for param in params.split(',').map(|param| param.trim()).filter(|param| !param.is_empty()) {
+        let (param_name, ty) = param.split_once(':').unwrap();
+        c_params.push(format!("{} {}", c_type(ty.trim()), param_name.trim()));
+    }
+    if c_params.is_empty() {
+        c_params.push(String::from("void"));
+    }
+    return format!("{return_type} {name}({});", c_params.join(", "));
+}
+
+
+
+fn c_type(rust: &str) -> String {
+    if let Some(pointee) = rust.strip_prefix("*const ") {
+        return format!("const {}*", c_type(pointee));
+    }
+    if let Some(pointee) = rust.strip_prefix("*mut ") {
+        return format!("{}*", c_type(pointee));
+    }
+    let c = match rust {
+        "i32" => "int32_t",
+        "i64" => "int64_t",
+        "u8" => "uint8_t",
+        "u32" => "uint32_t",
+        "u64" => "uint64_t",
+        "usize" => "size_t",
+        "bool" => "bool",
+        "c_char" => "char",
+        _ => panic!("no C type for {rust}"),
+    };
+    return c.to_string();
+}
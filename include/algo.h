@@ -0,0 +1,106 @@
+// Generated from src/ffi.rs by build.rs. Do not edit.
+//
+// C interface to the sorts, the number theory routines and the knapsack
+// solvers.
+//
+// Every function returns ALGO_OK or a negative error code and writes its
+// results through pointers; panics are caught and reported as
+// ALGO_ERR_PANIC instead of unwinding into C.
+//
+// Ownership: buffers passed in belong to the caller and are only used
+// during the call. A pointer may be NULL when its length is 0. The only
+// buffer the library hands out, from algo_find_factors, must be released
+// with algo_free_i64.
+
+#ifndef ALGO_H
+#define ALGO_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define ALGO_OK 0
+
+// A required pointer was NULL.
+#define ALGO_ERR_NULL_POINTER (-1)
+#define ALGO_ERR_INVALID_ARGUMENT (-2)
+
+// The result does not fit in its type.
+#define ALGO_ERR_OVERFLOW (-3)
+#define ALGO_ERR_NOT_INVERTIBLE (-4)
+
+// A counting sort key is outside [0, max).
+#define ALGO_ERR_KEY_OUT_OF_RANGE (-5)
+
+// A bug in the library; please report it.
+#define ALGO_ERR_PANIC (-6)
+
+// The solvers algo_knapsack can use.
+#define ALGO_KNAPSACK_EXHAUSTIVE 0
+#define ALGO_KNAPSACK_BRANCH_AND_BOUND 1
+#define ALGO_KNAPSACK_RODS 2
+#define ALGO_KNAPSACK_RODS_SORTED 3
+#define ALGO_KNAPSACK_DYNAMIC_PROGRAMMING 4
+
+// Return a description of an error code. The string is static; do not free it.
+const char* algo_error_message(int32_t code);
+
+// Sort len values in place.
+int32_t algo_quicksort_i32(int32_t* data, size_t len);
+
+// Sort len values in place.
+int32_t algo_quicksort_i64(int64_t* data, size_t len);
+
+// Sort len values in [0, max) in place. On ALGO_ERR_KEY_OUT_OF_RANGE the
+// data is left unchanged.
+int32_t algo_counting_sort_i32(int32_t* data, size_t len, int32_t max);
+
+// Sort len values in [0, max) in place. On ALGO_ERR_KEY_OUT_OF_RANGE the
+// data is left unchanged.
+int32_t algo_counting_sort_i64(int64_t* data, size_t len, int64_t max);
+
+// The greatest common divisor, which is never negative. Fails with
+// ALGO_ERR_OVERFLOW if it is 2^63, as when a is INT64_MIN and b is 0.
+int32_t algo_gcd(int64_t a, int64_t b, int64_t* result);
+
+// The least common multiple, which is never negative. It is 0 if a or b is.
+int32_t algo_lcm(int64_t a, int64_t b, int64_t* result);
+
+// num^pow mod modulus, in [0, modulus). modulus must be positive and pow
+// must not be negative.
+int32_t algo_fast_exp_mod(int64_t num, int64_t pow, int64_t modulus, int64_t* result);
+
+// The x in [0, n) with a * x = 1 mod n. Fails with ALGO_ERR_NOT_INVERTIBLE
+// if a and n share a factor.
+int32_t algo_inverse_mod(int64_t a, int64_t n, int64_t* result);
+
+// Whether p passes num_tests Fermat tests with bases drawn from a generator
+// seeded with seed. A prime always passes; a composite passes each test
+// with probability at most 1/2, except for Carmichael numbers.
+int32_t algo_is_probable_prime(int32_t p, int32_t num_tests, uint64_t seed, bool* result);
+
+// The prime factors of num > 0 in increasing order. *factors is set to a
+// new buffer of *len values, which the caller must release with
+// algo_free_i64(*factors, *len). Nothing is allocated on failure.
+int32_t algo_find_factors(int64_t num, int64_t** factors, size_t* len);
+
+// Release a buffer returned by algo_find_factors. NULL is ignored.
+void algo_free_i64(int64_t* data, size_t len);
+
+// Solve the 0/1 knapsack problem for len items with the given values and
+// weights, all non-negative, with one of the ALGO_KNAPSACK_ solvers.
+// selected must hold (len + 7) / 8 bytes; item i is selected if bit i % 8
+// of selected[i / 8] is set. The exhaustive and rods solvers take time
+// exponential in len, the dynamic programming solver time and memory
+// proportional to len * allowed_weight.
+int32_t algo_knapsack(int32_t solver, const int32_t* values, const int32_t* weights, size_t len, int32_t allowed_weight, uint8_t* selected, int32_t* total_value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // ALGO_H
@@ -0,0 +1,154 @@
+//! A small command-line parser: positional words plus "--name value",
+//! "--name=value" and bare "--flag" options. The caller names its flags,
+//! so the word after one stays positional. Values that were not given
+//! can be asked for, from the keyboard or from a --script file.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::{parse, Error, Result};
+use crate::prompt::{Prompt, Prompter};
+use crate::rng::{clock_seed, make_source, RandomSource};
+
+
+
+pub struct Args {
+    pub positional: Vec<String>,
+    pub options: HashMap<String, String>,
+    // Made on the first question, from --script and --history.
+    prompter: RefCell<Option<Prompter>>,
+}
+
+impl Args {
+    // Parse the words after the program name. The options named in flags
+    // take no value; any other option takes the word after it, unless that
+    // is another option.
+    pub fn parse(words: Vec<String>, flags: &[&str]) -> Self {
+        let mut positional: Vec<String> = vec![];
+        let mut options: HashMap<String, String> = HashMap::new();
+        let mut i = 0;
+        while i < words.len() {
+            if let Some(name) = words[i].strip_prefix("--") {
+                if let Some((name, value)) = name.split_once('=') {
+                    options.insert(name.to_string(), value.to_string());
+                } else if !flags.contains(&name) && i + 1 < words.len() && !words[i + 1].starts_with("--") {
+                    options.insert(name.to_string(), words[i + 1].clone());
+                    i += 1;
+                } else {
+                    // A bare flag.
+                    options.insert(name.to_string(), String::from("true"));
+                }
+            } else {
+                positional.push(words[i].clone());
+            }
+            i += 1;
+        }
+        return Self {
+            positional,
+            options,
+            prompter: RefCell::new(None),
+        };
+    }
+
+    // Parse the process's own arguments.
+    pub fn from_env(flags: &[&str]) -> Self {
+        return Self::parse(env::args().skip(1).collect(), flags);
+    }
+
+    // Return the value of --name, if given.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        return self.options.get(name).map(|value| value.as_str());
+    }
+
+    // Return true if --name was given as a bare flag, or as --name=true.
+    pub fn flag(&self, name: &str) -> bool {
+        return self.value(name) == Some("true");
+    }
+
+    // Return the positional word at index i, if there is one.
+    pub fn positional(&self, i: usize) -> Option<&str> {
+        return self.positional.get(i).map(|word| word.as_str());
+    }
+
+    // Return --name parsed as T, if given.
+    pub fn parsed<T: FromStr>(&self, name: &str) -> Result<Option<T>> {
+        return self.value(name).map(|value| parse(value, &format!("value for --{name}"))).transpose();
+    }
+
+    // Return --name parsed as T, or default if it was not given.
+    pub fn parsed_or<T: FromStr>(&self, name: &str, default: T) -> Result<T> {
+        return Ok(self.parsed(name)?.unwrap_or(default));
+    }
+
+    // Build the generator named by --rng, seeded from --seed, default_seed
+    // or the clock, and print both so the run can be repeated. They go to
+    // stderr when --format asks for records, to keep stdout parseable.
+    pub fn source(&self, default_seed: Option<u32>) -> Result<Box<dyn RandomSource>> {
+        let seed = self.parsed::<u32>("seed")?.or(default_seed).unwrap_or_else(clock_seed);
+        let rng = self.value("rng").unwrap_or("lcg");
+        if self.value("format").unwrap_or("text") == "text" {
+            println!("Seed: {seed}, Generator: {rng}");
+        } else {
+            eprintln!("Seed: {seed}, Generator: {rng}");
+        }
+        return make_source(rng, seed as u64).ok_or_else(|| Error::InvalidArgument(format!("unknown generator {rng}")));
+    }
+
+    // Ask for these args' answers with the given prompter instead of one
+    // made from --script and --history.
+    pub fn with_prompter(self, prompter: Prompter) -> Self {
+        *self.prompter.borrow_mut() = Some(prompter);
+        return self;
+    }
+
+    // Ask the prompt, replaying --script if it was given and recording the
+    // answer to --history if that was given.
+    pub fn ask<T: FromStr + PartialOrd + Display + Clone>(&self, prompt: &Prompt<T>) -> Result<T> {
+        let mut slot = self.prompter.borrow_mut();
+        let prompter = match slot.take() {
+            Some(prompter) => slot.insert(prompter),
+            None => slot.insert(Prompter::from_options(self.value("script"), self.value("history"))?),
+        };
+        return prompter.ask(prompt);
+    }
+
+    // Return --name checked against the prompt's range, or ask for it if it
+    // was not given.
+    pub fn or_ask<T: FromStr + PartialOrd + Display + Clone>(&self, name: &str, prompt: Prompt<T>) -> Result<T> {
+        return match self.value(name) {
+            Some(value) => check_option(name, value, &prompt),
+            None => self.ask(&prompt),
+        };
+    }
+
+    // Return the positional word at index i or else --name, checked against
+    // the prompt's range, asking for it if neither was given.
+    pub fn positional_or_ask<T: FromStr + PartialOrd + Display + Clone>(
+        &self,
+        i: usize,
+        name: &str,
+        prompt: Prompt<T>,
+    ) -> Result<T> {
+        return match self.positional(i) {
+            Some(word) => check_option(name, word, &prompt),
+            None => self.or_ask(name, prompt),
+        };
+    }
+}
+
+
+
+// Check a value given on the command line, naming the option in the error.
+fn check_option<T: FromStr + PartialOrd + Display + Clone>(name: &str, value: &str, prompt: &Prompt<T>) -> Result<T> {
+    return prompt.check(value).map_err(|error| match error {
+        Error::ParseError { input, .. } => Error::ParseError {
+            input,
+            expected: format!("value for --{name}"),
+        },
+        Error::InvalidArgument(message) => Error::InvalidArgument(format!("--{name}: {message}")),
+        error => error,
+    });
+}
@@ -0,0 +1,93 @@
+use crate::metrics::{Counter, Metrics};
+
+
+
+// Make three posts with num_disks entries and put the disks on the
+// first post in order, smallest first (on top).
+pub fn make_posts(num_disks: usize) -> Vec<Vec<usize>> {
+    let mut posts = vec![vec![0; num_disks]; 3];
+    for i in 0..num_disks {
+        posts[0][i] = i + 1;
+    }
+    return posts;
+}
+
+
+
+// Draw the posts by showing the size of the disk at each level.
+pub fn draw_posts(posts: &Vec<Vec<usize>>) {
+    for row in 0..posts[0].len() {
+        // Draw this row.
+        for post_num in 0..3 {
+            // Draw the disk on post p's row.
+            print!("{} ", posts[post_num][row]);
+        }
+        println!();
+    }
+
+    // Draw a line between moves.
+    println!("-----");
+}
+
+
+
+// Move one disk from from_post to to_post.
+pub fn move_disk(posts: &mut Vec<Vec<usize>>, from_post: usize, to_post: usize) {
+    let num_disks = posts[0].len();
+
+    // Find the first non-empty row in from_post.
+    let mut from_row = 0usize;
+    for row in 0..num_disks {
+        if posts[from_post][row] != 0 {
+            from_row = row;
+            break;
+        }
+    }
+
+    // Find the last empty row in to_post.
+    let mut to_row = num_disks - 1;
+    for row in 0..num_disks {
+        if posts[to_post][row] != 0 {
+            to_row = row - 1;
+            break;
+        }
+    }
+
+    // Swap the values at those positions.
+    (posts[from_post][from_row], posts[to_post][to_row]) =
+        (posts[to_post][to_row], posts[from_post][from_row]);
+}
+
+
+
+// Move the disks from from_post to to_post
+// using temp_post as temporary storage.
+// If draw is true, draw the posts after every move.
+// Each disk moved counts as a move.
+pub fn move_disks(
+    posts: &mut Vec<Vec<usize>>,
+    num_to_move: usize,
+    from_post: usize,
+    to_post: usize,
+    temp_post: usize,
+    draw: bool,
+    metrics: &Metrics,
+) {
+    metrics.trace(
+        || format!("move_disks({num_to_move}, {from_post} -> {to_post})"),
+        || {
+            if num_to_move > 0 {
+                move_disks(posts, num_to_move - 1, from_post, temp_post, to_post, draw, metrics);
+                move_disk(posts, from_post, to_post);
+                metrics.count(Counter::Moves);
+                if draw {
+                    draw_posts(posts);
+                }
+                if num_to_move > 1 {
+                    move_disks(posts, num_to_move - 1, temp_post, to_post, from_post, draw, metrics);
+                }
+            }
+        },
+        |_| String::new(),
+    );
+}
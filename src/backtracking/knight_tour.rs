@@ -0,0 +1,113 @@
+use crate::metrics::{Counter, Metrics};
+
+// Value to represent a square that we have not visited.
+pub const UNVISITED: i32 = -1;
+
+// The 8 possible knight moves, 2 coordinates each.
+pub const OFFSETS: [[i32; 2]; 8] = [
+    [-2, -1],
+    [-1, -2],
+    [2, -1],
+    [1, -2],
+    [-2, 1],
+    [-1, 2],
+    [2, 1],
+    [1, 2],
+];
+
+
+
+// Create a num_rows x num_cols board with all entries initialized to UNVISITED.
+pub fn make_board(num_rows: usize, num_cols: usize) -> Vec<Vec<i32>> {
+    return vec![vec![UNVISITED; num_cols]; num_rows];
+}
+
+
+
+// Display the board.
+pub fn dump_board(board: &Vec<Vec<i32>>) {
+    for r in 0..board.len() {
+        for c in 0..board[r].len() {
+            print!("{:<02} ", board[r][c]);
+        }
+        println!();
+    }
+    println!();
+}
+
+
+
+// Try to extend a knight's tour starting at (start_row, start_col).
+// Return true or false to indicate whether we have found a solution.
+// If the metrics' budget runs out, return false and leave the moves made
+// so far on the board.
+pub fn find_tour(
+    board: &mut Vec<Vec<i32>>,
+    offsets: &[[i32; 2]; 8], // 8 possible moves, 2 coordinates each.
+    cur_row: i32,
+    cur_col: i32,
+    num_visited: i32,
+    require_closed_tour: bool,
+    metrics: &Metrics,
+) -> bool {
+    let inum_rows = board.len() as i32;
+    let inum_cols = board[0].len() as i32;
+
+    metrics.count(Counter::Calls);
+    metrics.progress("knight", num_visited as usize, None);
+    return metrics.trace(
+        || format!("find_tour({cur_row}, {cur_col}, {num_visited})"),
+        || {
+            if num_visited == inum_rows * inum_cols {
+                if require_closed_tour == false {
+                    return true;
+                } else {
+                    for m in offsets.iter() {
+                        let row = cur_row + m[0];
+                        let col = cur_col + m[1];
+                        if row >= 0
+                            && row < inum_rows
+                            && col >= 0
+                            && col < inum_cols
+                            && board[row as usize][col as usize] == 0
+                        {
+                            return true;
+                        }
+                    }
+                    return false;
+                }
+            } else if metrics.out_of_budget() {
+                return false;
+            } else {
+                for m in offsets.iter() {
+                    let row = cur_row + m[0];
+                    let col = cur_col + m[1];
+
+                    //skip where target is off board or already visited
+                    if row < 0
+                        || row >= inum_rows
+                        || col < 0
+                        || col >= inum_cols
+                        || board[row as usize][col as usize] != UNVISITED
+                    {
+                        continue;
+                    }
+
+                    //valid row and col giving unvisited target
+                    board[row as usize][col as usize] = num_visited;
+                    if find_tour(board, offsets, row, col, num_visited + 1, require_closed_tour, metrics) == true {
+                        return true;
+                    }
+                    if metrics.stop_reason().is_some() {
+                        return false;
+                    }
+
+                    //backtrack - undo the move as this state can't find solution
+                    board[row as usize][col as usize] = UNVISITED;
+                }
+                return false;
+            }
+        },
+        |found| found.to_string(),
+    );
+}
@@ -0,0 +1,5 @@
+//! Backtracking searches: the knight's tour, N queens and the Tower of Hanoi.
+
+pub mod hanoi;
+pub mod knight_tour;
+pub mod nqueens;
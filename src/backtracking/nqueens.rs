@@ -0,0 +1,208 @@
+use crate::metrics::{Counter, Metrics};
+
+
+
+// Create a num_rows x num_rows board with no queens on it.
+pub fn make_board(num_rows: usize) -> Vec<Vec<char>> {
+    return vec![vec!['.'; num_rows]; num_rows];
+}
+
+
+
+// Display the board.
+pub fn dump_board(board: &Vec<Vec<char>>) {
+    for r in 0..board.len() {
+        for c in 0..board[r].len() {
+            print!("{:<02}", board[r][c]);
+        }
+        println!();
+    }
+    println!();
+}
+
+
+
+// Return true if this series of squares contains at most one queen.
+pub fn series_is_legal(board: &Vec<Vec<char>>, r0: i32, c0: i32, dr: i32, dc: i32) -> bool {
+    let inum_rows = board.len() as i32;
+    let inum_cols = inum_rows;
+    let mut has_queen = false;
+
+    let mut r = r0;
+    let mut c = c0;
+    loop {
+        if board[r as usize][c as usize] == 'Q' {
+            // If we already have a queen on this row,
+            // then this board is not legal.
+            if has_queen {
+                return false;
+            }
+
+            // Remember that we have a queen on this row.
+            has_queen = true;
+        }
+
+        // Move to the next square in the series.
+        r += dr;
+        c += dc;
+
+        // If we fall off the board, then the series is legal.
+        if r >= inum_rows || c >= inum_cols || r < 0 || c < 0 {
+            return true;
+        }
+    }
+}
+
+
+
+// Return true if the board is legal.
+pub fn board_is_legal(board: &Vec<Vec<char>>) -> bool {
+    let inum_rows = board.len() as i32;
+    let inum_cols = inum_rows;
+
+    // See if each row is legal.
+    for r in 0..inum_rows {
+        if !series_is_legal(board, r, 0, 0, 1) {
+            return false;
+        }
+    }
+
+    // See if each column is legal.
+    for c in 0..inum_cols {
+        if !series_is_legal(board, 0, c, 1, 0) {
+            return false;
+        }
+    }
+
+    // See if diagonals down to the right are legal.
+    for r in 0..inum_rows {
+        if !series_is_legal(board, r, 0, 1, 1) {
+            return false;
+        }
+    }
+    for c in 0..inum_cols {
+        if !series_is_legal(board, 0, c, 1, 1) {
+            return false;
+        }
+    }
+
+    // See if diagonals down to the left are legal.
+    for r in 0..inum_rows {
+        if !series_is_legal(board, r, inum_rows - 1, 1, -1) {
+            return false;
+        }
+    }
+    for c in 0..inum_cols {
+        if !series_is_legal(board, 0, c, 1, -1) {
+            return false;
+        }
+    }
+
+    // If we survived this long, then the board is legal.
+    return true;
+}
+
+
+
+// Return true if the board is legal and a solution.
+pub fn board_is_a_solution(board: &Vec<Vec<char>>) -> bool {
+    // See if it is legal.
+    if !board_is_legal(board) {
+        return false;
+    }
+
+    // See if the board contains exactly num_rows queens.
+    let mut num_queens = 0;
+    for r in 0..board.len() {
+        for c in 0..board[r].len() {
+            if board[r][c] == 'Q' {
+                num_queens += 1;
+            }
+        }
+    }
+    return num_queens == board.len();
+}
+
+
+
+// Try placing a queen at position [r][c].
+// Return true if we find a legal board. If the metrics' budget runs out,
+// return false and leave the queens placed so far on the board.
+pub fn place_queens_1(board: &mut Vec<Vec<char>>, r: i32, c: i32, metrics: &Metrics) -> bool {
+    metrics.count(Counter::Calls);
+    metrics.progress("queens", (r as usize) * board.len() + c as usize, None);
+    return metrics.trace(
+        || format!("place_queens_1({r}, {c})"),
+        || {
+            let inum_rows = board.len() as i32;
+            if r >= inum_rows {
+                return board_is_a_solution(board);
+            }
+
+            // Find the next square.
+            let mut next_r = r;
+            let mut next_c = c + 1;
+            if next_c >= inum_rows {
+                next_r += 1;
+                next_c = 0;
+            }
+
+            if metrics.out_of_budget() {
+                return false;
+            }
+            if place_queens_1(board, next_r, next_c, metrics) {
+                return true;
+            }
+            if metrics.stop_reason().is_some() {
+                return false;
+            }
+
+            board[r as usize][c as usize] = 'Q';
+            if place_queens_1(board, next_r, next_c, metrics) {
+                return true;
+            }
+            if metrics.stop_reason().is_some() {
+                return false;
+            }
+
+            board[r as usize][c as usize] = '.';
+            return false;
+        },
+        |found| found.to_string(),
+    );
+}
+
+
+
+// Try to place a queen in this column.
+// Return true if we find a legal board.
+// Partial boards that are already illegal count as prunes.
+// If the metrics' budget runs out, return false and leave the queens
+// placed so far, which are always a legal partial board.
+pub fn place_queens_4(board: &mut Vec<Vec<char>>, c: i32, metrics: &Metrics) -> bool {
+    metrics.count(Counter::Calls);
+    metrics.progress("queens", c as usize, None);
+    let inum_rows = board.len() as i32;
+    if c == inum_rows {
+        return board_is_a_solution(board);
+    } else { //c will be < inum_rows
+        if !board_is_legal(board) {
+            metrics.count(Counter::Prunes);
+            return false;
+        }
+        if metrics.out_of_budget() {
+            return false;
+        }
+        for r in 0..inum_rows {
+            board[r as usize][c as usize] = 'Q';
+            if place_queens_4(board, c + 1, metrics) {
+                return true;
+            } else if metrics.stop_reason().is_some() {
+                return false;
+            } else {
+                board[r as usize][c as usize] = '.';
+            }
+        }
+        return false;
+    }
+}
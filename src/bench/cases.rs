@@ -0,0 +1,183 @@
+use crate::knapsack::{
+    branch_and_bound, dynamic_programming, exhaustive_search, make_items, rods_technique,
+    rods_technique_sorted, sum_weights, Solver,
+};
+use crate::metrics::Metrics;
+use crate::rng::Sampling;
+use crate::searching::{binary_search, linear_search};
+use crate::sorting::{
+    bubble_sort, counting_sort, heapsort, insertion_sort, lsd_radix_sort, make_random_customers, make_random_vec,
+    quicksort, quicksort_with, Digits, Partition,
+};
+
+use super::{measure, Case};
+
+
+
+type SortFn = fn(&mut [i32], &Metrics);
+
+// The comparison sorts, with quicksort's three-way partitions as well.
+const SORTS: [(&str, SortFn); 6] = [
+    ("bubble", bubble_sort),
+    ("insertion", insertion_sort),
+    ("quick", quicksort),
+    ("quick-dijkstra", |vec, metrics| quicksort_with(vec, Partition::Dijkstra, metrics)),
+    ("quick-bm", |vec, metrics| quicksort_with(vec, Partition::BentleyMcIlroy, metrics)),
+    ("heap", heapsort),
+];
+
+// The comparison sorts, each on n random values in [0, n) put in order by
+// arrange before the run.
+fn arranged_sort_cases(arrange: fn(&mut Vec<i32>)) -> Vec<Case> {
+    return SORTS
+        .into_iter()
+        .map(|(name, sort)| {
+            Case::new(name, move |prng, n| {
+                let mut vec = make_random_vec(prng, n as i32, n as i32);
+                arrange(&mut vec);
+                return measure(|| {
+                    let metrics = Metrics::new();
+                    sort(&mut vec, &metrics);
+                    return Ok(metrics.counters());
+                });
+            })
+        })
+        .collect();
+}
+
+
+
+// The sorts, on n random values in [0, n).
+pub fn sort_cases() -> Vec<Case> {
+    let mut cases = arranged_sort_cases(|_| {});
+    cases.push(Case::new("counting", |prng, n| {
+        let max = (n as i32).max(1);
+        let vec = make_random_customers(prng, n as i32, max);
+        return measure(|| {
+            counting_sort(&vec, max)?;
+            return Ok(vec![]);
+        });
+    }));
+    for (name, digits) in [("radix", Digits::Bytes), ("radix-11", Digits::Bits11)] {
+        cases.push(Case::new(name, move |prng, n| {
+            let mut vec = make_random_vec(prng, n as i32, n as i32);
+            return measure(|| {
+                let metrics = Metrics::new();
+                lsd_radix_sort(&mut vec, digits, &metrics)?;
+                return Ok(metrics.counters());
+            });
+        }));
+    }
+    return cases;
+}
+
+
+
+// The comparison sorts again, on input that is already sorted, reversed,
+// or in organ-pipe order (rising then falling), to show worst cases.
+pub fn presorted_cases() -> Vec<Case> {
+    return arranged_sort_cases(|vec| vec.sort());
+}
+
+pub fn reversed_cases() -> Vec<Case> {
+    return arranged_sort_cases(|vec| vec.sort_by(|a, b| b.cmp(a)));
+}
+
+// The comparison sorts on n values with only four distinct keys, where
+// the three-way quicksorts are linear.
+pub fn few_keys_cases() -> Vec<Case> {
+    return arranged_sort_cases(|vec| {
+        for value in vec.iter_mut() {
+            *value %= 4;
+        }
+    });
+}
+
+pub fn organ_pipe_cases() -> Vec<Case> {
+    return arranged_sort_cases(|vec| {
+        vec.sort();
+        // The even positions rising, then the odd ones falling.
+        let mut arranged: Vec<i32> = vec.iter().step_by(2).copied().collect();
+        arranged.extend(vec.iter().skip(1).step_by(2).rev());
+        *vec = arranged;
+    });
+}
+
+
+
+// The searches, for a random value taken from n random values in [0, 2n).
+pub fn search_cases() -> Vec<Case> {
+    return vec![
+        Case::new("linear", |prng, n| {
+            let vec = make_random_vec(prng, n.max(1) as i32, 2 * n.max(1) as i32);
+            let target = vec[prng.below_u64(vec.len() as u64) as usize];
+            return measure(|| {
+                let metrics = Metrics::new();
+                linear_search(&vec, target, &metrics);
+                return Ok(metrics.counters());
+            });
+        }),
+        Case::new("binary", |prng, n| {
+            let mut vec = make_random_vec(prng, n.max(1) as i32, 2 * n.max(1) as i32);
+            vec.sort();
+            let target = vec[prng.below_u64(vec.len() as u64) as usize];
+            return measure(|| {
+                let metrics = Metrics::new();
+                let _ = binary_search(&vec, target, &metrics);
+                return Ok(metrics.counters());
+            });
+        }),
+    ];
+}
+
+
+
+// The knapsack solvers, on n items like the dp_* programs make, with half
+// the total weight allowed.
+pub fn knapsack_cases() -> Vec<Case> {
+    let solvers: [(&str, &'static Solver); 5] = [
+        ("exhaustive", &exhaustive_search),
+        ("bnb", &branch_and_bound),
+        ("rods", &rods_technique),
+        ("rods-sorted", &rods_technique_sorted),
+        ("dp", &dynamic_programming),
+    ];
+    return solvers
+        .into_iter()
+        .map(|(name, solver)| {
+            Case::new(name, move |prng, n| {
+                let mut items = make_items(prng, n as i32, 1, 10, 4, 10);
+                let allowed_weight = sum_weights(&items, true)? / 2;
+                return measure(|| {
+                    let metrics = Metrics::new();
+                    solver(&mut items, allowed_weight, &metrics)?;
+                    return Ok(metrics.counters());
+                });
+            })
+        })
+        .collect();
+}
+
+
+
+// Return the cases of a suite: "sort", "presorted", "reversed",
+// "organ-pipe", "few-keys", "search" or "knapsack".
+pub fn suite(name: &str) -> Option<Vec<Case>> {
+    return match name {
+        "sort" => Some(sort_cases()),
+        "presorted" => Some(presorted_cases()),
+        "reversed" => Some(reversed_cases()),
+        "organ-pipe" => Some(organ_pipe_cases()),
+        "few-keys" => Some(few_keys_cases()),
+        "search" => Some(search_cases()),
+        "knapsack" => Some(knapsack_cases()),
+        _ => None,
+    };
+}
+
+
+
+// Return one case of a suite by name.
+pub fn find_case(suite_name: &str, name: &str) -> Option<Case> {
+    return suite(suite_name)?.into_iter().find(|case| case.name == name);
+}
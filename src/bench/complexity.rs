@@ -0,0 +1,262 @@
+//! Empirical complexity: sweep the input size and fit growth models to the
+//! measured time or to one of the algorithm's counters.
+
+use crate::error::Result;
+use crate::output::Record;
+
+use super::{bench, format_seconds, Case, Settings};
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Model {
+    Constant,
+    Log,
+    Linear,
+    NLogN,
+    Quadratic,
+    Exponential,
+}
+
+impl Model {
+    pub const ALL: [Model; 6] = [
+        Model::Constant,
+        Model::Log,
+        Model::Linear,
+        Model::NLogN,
+        Model::Quadratic,
+        Model::Exponential,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Model::Constant => "O(1)",
+            Model::Log => "O(log n)",
+            Model::Linear => "O(n)",
+            Model::NLogN => "O(n log n)",
+            Model::Quadratic => "O(n²)",
+            Model::Exponential => "O(2ⁿ)",
+        };
+    }
+
+    // The natural log of the model's growth function. Working with logs
+    // keeps 2ⁿ from overflowing. log n is taken as at least 1.
+    pub fn ln_growth(&self, n: usize) -> f64 {
+        let n = n.max(1) as f64;
+        let log2 = n.log2().max(1.0);
+        return match self {
+            Model::Constant => 0.0,
+            Model::Log => log2.ln(),
+            Model::Linear => n.ln(),
+            Model::NLogN => n.ln() + log2.ln(),
+            Model::Quadratic => 2.0 * n.ln(),
+            Model::Exponential => n * 2f64.ln(),
+        };
+    }
+}
+
+
+
+// A model fitted as value ≈ coefficient · f(n). The residuals are the logs
+// of measured / predicted, so they are relative errors whatever the scale.
+pub struct Fit {
+    pub model: Model,
+    pub coefficient: f64,
+    pub residuals: Vec<f64>,
+    // Root mean square of the residuals.
+    pub rms: f64,
+}
+
+impl Fit {
+    pub fn predict(&self, n: usize) -> f64 {
+        return self.coefficient * self.model.ln_growth(n).exp();
+    }
+}
+
+
+
+// Fit one model by least squares on the logs.
+pub fn fit(model: Model, sizes: &[usize], values: &[f64]) -> Fit {
+    assert_eq!(sizes.len(), values.len(), "one value per size");
+    assert!(!sizes.is_empty(), "nothing to fit");
+    let offsets: Vec<f64> = (0..sizes.len())
+        .map(|i| ln_value(values[i]) - model.ln_growth(sizes[i]))
+        .collect();
+    let ln_coefficient = offsets.iter().sum::<f64>() / offsets.len() as f64;
+    let residuals: Vec<f64> = offsets.iter().map(|offset| offset - ln_coefficient).collect();
+    let rms = (residuals.iter().map(|r| r * r).sum::<f64>() / residuals.len() as f64).sqrt();
+    return Fit {
+        model,
+        coefficient: ln_coefficient.exp(),
+        residuals,
+        rms,
+    };
+}
+
+
+
+// Fit every model, best first.
+pub fn fit_all(sizes: &[usize], values: &[f64]) -> Vec<Fit> {
+    let mut fits: Vec<Fit> = Model::ALL
+        .iter()
+        .map(|model| fit(*model, sizes, values))
+        .collect();
+    fits.sort_by(|a, b| a.rms.total_cmp(&b.rms));
+    return fits;
+}
+
+
+
+// The slope of ln value against ln n: the k in n^k.
+pub fn power_exponent(sizes: &[usize], values: &[f64]) -> f64 {
+    let xs: Vec<f64> = sizes.iter().map(|n| ((*n).max(1) as f64).ln()).collect();
+    let ys: Vec<f64> = values.iter().map(|v| ln_value(*v)).collect();
+    return slope(&xs, &ys);
+}
+
+
+
+// The b in bⁿ, from the slope of ln value against n.
+pub fn exponential_base(sizes: &[usize], values: &[f64]) -> f64 {
+    let xs: Vec<f64> = sizes.iter().map(|n| *n as f64).collect();
+    let ys: Vec<f64> = values.iter().map(|v| ln_value(*v)).collect();
+    return slope(&xs, &ys).exp();
+}
+
+
+
+pub struct Estimate {
+    pub name: String,
+    // "time" or the name of a counter.
+    pub metric: String,
+    pub sizes: Vec<usize>,
+    pub values: Vec<f64>,
+    // Every model, best first.
+    pub fits: Vec<Fit>,
+}
+
+impl Estimate {
+    pub fn best(&self) -> &Fit {
+        return &self.fits[0];
+    }
+
+    // One record per model, best first.
+    pub fn records(&self) -> Vec<Record> {
+        return self
+            .fits
+            .iter()
+            .enumerate()
+            .map(|(rank, fit)| {
+                Record::new("complexity")
+                    .with("name", self.name.as_str())
+                    .with("metric", self.metric.as_str())
+                    .with("rank", rank + 1)
+                    .with("model", fit.model.name())
+                    .with("coefficient", fit.coefficient)
+                    .with("rms", fit.rms)
+            })
+            .collect();
+    }
+}
+
+
+
+// Run the case at each size, smallest first, and fit the median time or
+// the mean of the named counter. The sweep stops after a size that goes
+// over the budget. Panics if fewer than three sizes could be measured or
+// the counter does not exist.
+pub fn estimate(case: &Case, sizes: &[usize], metric: &str, settings: &Settings) -> Result<Estimate> {
+    let mut sorted_sizes = sizes.to_vec();
+    sorted_sizes.sort();
+    let mut measured: Vec<usize> = vec![];
+    let mut values: Vec<f64> = vec![];
+    for size in sorted_sizes {
+        let result = bench(case, size, settings)?;
+        let value = if metric == "time" {
+            result.summary.median
+        } else {
+            match result.counters.iter().find(|(name, _)| *name == metric) {
+                Some((_, value)) => *value,
+                None => panic!("{} has no counter named {}", case.name, metric),
+            }
+        };
+        measured.push(size);
+        values.push(value);
+        if result.over_budget {
+            break;
+        }
+    }
+    assert!(measured.len() >= 3, "need at least three sizes to fit a model");
+
+    let fits = fit_all(&measured, &values);
+    return Ok(Estimate {
+        name: case.name.clone(),
+        metric: metric.to_string(),
+        sizes: measured,
+        values,
+        fits,
+    });
+}
+
+
+
+// Print the measurements, the models ranked by fit and the best fit's
+// residuals as measured / predicted factors.
+pub fn print_estimate(estimate: &Estimate) {
+    let best = estimate.best();
+    println!("*** {} ({}) ***", estimate.name, estimate.metric);
+    println!("{:>10} {:>14} {:>14} {:>9}", "n", "measured", "predicted", "factor");
+    for i in 0..estimate.sizes.len() {
+        let n = estimate.sizes[i];
+        println!(
+            "{:>10} {:>14} {:>14} {:>9.3}",
+            n,
+            format_metric(&estimate.metric, estimate.values[i]),
+            format_metric(&estimate.metric, best.predict(n)),
+            best.residuals[i].exp()
+        );
+    }
+    println!();
+    for fit in estimate.fits.iter() {
+        println!("{:<11} rms {:.4}", fit.model.name(), fit.rms);
+    }
+    println!(
+        "Power fit: n^{:.2}, exponential fit: {:.3}^n",
+        power_exponent(&estimate.sizes, &estimate.values),
+        exponential_base(&estimate.sizes, &estimate.values)
+    );
+    println!("Best fit: {}", best.model.name());
+}
+
+
+
+fn format_metric(metric: &str, value: f64) -> String {
+    if metric == "time" {
+        return format_seconds(value);
+    }
+    return format!("{:.1}", value);
+}
+
+
+
+// The log of a measured value. Zero counts and times are clamped so the
+// fit stays finite.
+fn ln_value(value: f64) -> f64 {
+    return value.max(1e-12).ln();
+}
+
+
+
+// Least-squares slope of ys against xs.
+fn slope(xs: &[f64], ys: &[f64]) -> f64 {
+    let n = xs.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / n;
+    let mean_y = ys.iter().sum::<f64>() / n;
+    let mut sxy = 0.0;
+    let mut sxx = 0.0;
+    for i in 0..xs.len() {
+        sxy += (xs[i] - mean_x) * (ys[i] - mean_y);
+        sxx += (xs[i] - mean_x) * (xs[i] - mean_x);
+    }
+    return if sxx == 0.0 { 0.0 } else { sxy / sxx };
+}
@@ -0,0 +1,355 @@
+//! Repeated timing runs with summary statistics, for comparing algorithms.
+
+mod cases;
+pub mod complexity;
+
+pub use cases::{
+    few_keys_cases, find_case, knapsack_cases, organ_pipe_cases, presorted_cases, reversed_cases, search_cases,
+    sort_cases, suite,
+};
+
+use std::io;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::metrics::Counter;
+use crate::output::{Format, Record, Writer};
+use crate::rng::{make_source, RandomSource};
+
+// The algorithm's own counts for one run, as Metrics::counters returns them.
+pub type Counters = Vec<(&'static str, u64)>;
+
+// Builds a fresh input of the given size and measures one run on it.
+pub type RunFn = dyn Fn(&mut Box<dyn RandomSource>, usize) -> Result<Measurement>;
+
+
+
+// The time and counters for one run. Input generation is not timed.
+pub struct Measurement {
+    pub elapsed: Duration,
+    pub counters: Counters,
+}
+
+// Time a closure that runs the algorithm and returns its counters.
+pub fn measure(run: impl FnOnce() -> Result<Counters>) -> Result<Measurement> {
+    let start = Instant::now();
+    let counters = run()?;
+    let elapsed = start.elapsed();
+    return Ok(Measurement { elapsed, counters });
+}
+
+
+
+// A registered algorithm.
+pub struct Case {
+    pub name: String,
+    pub run: Box<RunFn>,
+}
+
+impl Case {
+    pub fn new(
+        name: &str,
+        run: impl Fn(&mut Box<dyn RandomSource>, usize) -> Result<Measurement> + 'static,
+    ) -> Self {
+        return Case {
+            name: name.to_string(),
+            run: Box::new(run),
+        };
+    }
+}
+
+
+
+pub struct Settings {
+    pub warmups: usize,
+    pub repetitions: usize,
+    pub seed: u64,
+    pub rng: String,
+    // Once a run takes longer than this, the case is not tried at larger sizes
+    // and the remaining repetitions at this size are skipped.
+    pub budget: Duration,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        return Settings {
+            warmups: 1,
+            repetitions: 10,
+            seed: 1337,
+            rng: String::from("lcg"),
+            budget: Duration::from_secs(1),
+        };
+    }
+}
+
+
+
+// Summary statistics of the run times, in seconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Summary {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+    pub p95: f64,
+}
+
+// Summarize a non-empty list of times. The standard deviation is the sample
+// standard deviation and the percentiles use the nearest-rank method.
+pub fn summarize(times: &[f64]) -> Summary {
+    assert!(!times.is_empty(), "no times to summarize");
+    let mut sorted = times.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let n = sorted.len();
+
+    let mean = sorted.iter().sum::<f64>() / n as f64;
+    let mut variance = 0.0;
+    if n > 1 {
+        variance = sorted.iter().map(|t| (t - mean) * (t - mean)).sum::<f64>() / (n - 1) as f64;
+    }
+    let median = if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+    };
+    let rank = ((0.95 * n as f64).ceil() as usize).max(1);
+
+    return Summary {
+        min: sorted[0],
+        median,
+        mean,
+        stddev: variance.sqrt(),
+        p95: sorted[rank - 1],
+    };
+}
+
+
+
+// The result of benchmarking one case at one size.
+pub struct BenchResult {
+    pub name: String,
+    pub size: usize,
+    pub repetitions: usize,
+    pub summary: Summary,
+    // The mean of each counter over the timed runs.
+    pub counters: Vec<(&'static str, f64)>,
+    // True if a run went over the budget.
+    pub over_budget: bool,
+}
+
+impl BenchResult {
+    // Every counter gets its own column, zero or not, as Metrics::add_to
+    // does, so the records of a comparison share one CSV header.
+    pub fn record(&self) -> Record {
+        let mut record = Record::new("bench")
+            .with("name", self.name.as_str())
+            .with("size", self.size)
+            .with("reps", self.repetitions)
+            .with("min", self.summary.min)
+            .with("median", self.summary.median)
+            .with("mean", self.summary.mean)
+            .with("stddev", self.summary.stddev)
+            .with("p95", self.summary.p95);
+        for counter in Counter::ALL {
+            record.push(counter.name(), self.counter(counter.name()));
+        }
+        for (name, value) in self.counters.iter() {
+            if !Counter::ALL.iter().any(|counter| counter.name() == *name) {
+                record.push(name, *value);
+            }
+        }
+        record.push("over_budget", self.over_budget);
+        return record;
+    }
+
+    // The mean of the named counter, or 0 if the runs never counted it.
+    fn counter(&self, name: &str) -> f64 {
+        return self
+            .counters
+            .iter()
+            .find(|(counter_name, _)| *counter_name == name)
+            .map_or(0.0, |(_, value)| *value);
+    }
+}
+
+
+
+// Benchmark one case at one size. Every repetition gets a fresh input, and
+// every case sees the same sequence of inputs for the same settings.
+pub fn bench(case: &Case, size: usize, settings: &Settings) -> Result<BenchResult> {
+    let mut prng = make_source(&settings.rng, settings.seed)
+        .ok_or_else(|| Error::InvalidArgument(format!("unknown generator {}", settings.rng)))?;
+
+    let mut over_budget = false;
+    for _ in 0..settings.warmups {
+        if (case.run)(&mut prng, size)?.elapsed > settings.budget {
+            over_budget = true;
+            break;
+        }
+    }
+
+    let mut times: Vec<f64> = vec![];
+    let mut totals: Vec<(&'static str, f64)> = vec![];
+    while times.len() < settings.repetitions.max(1) {
+        let measurement = (case.run)(&mut prng, size)?;
+        times.push(measurement.elapsed.as_secs_f64());
+        for (name, value) in measurement.counters {
+            match totals.iter_mut().find(|(total_name, _)| *total_name == name) {
+                Some((_, total)) => *total += value as f64,
+                None => totals.push((name, value as f64)),
+            }
+        }
+        if over_budget || measurement.elapsed > settings.budget {
+            over_budget = true;
+            break;
+        }
+    }
+
+    let repetitions = times.len();
+    return Ok(BenchResult {
+        name: case.name.clone(),
+        size,
+        repetitions,
+        summary: summarize(&times),
+        counters: totals
+            .into_iter()
+            .map(|(name, total)| (name, total / repetitions as f64))
+            .collect(),
+        over_budget,
+    });
+}
+
+
+
+// Benchmark the cases side by side at each size, smallest first. A case
+// that goes over budget is dropped for the larger sizes.
+pub fn compare(cases: &[Case], sizes: &[usize], settings: &Settings) -> Result<Vec<BenchResult>> {
+    let mut sorted_sizes = sizes.to_vec();
+    sorted_sizes.sort();
+    let mut active = vec![true; cases.len()];
+    let mut results: Vec<BenchResult> = vec![];
+    for size in sorted_sizes {
+        for i in 0..cases.len() {
+            if active[i] {
+                let result = bench(&cases[i], size, settings)?;
+                active[i] = !result.over_budget;
+                results.push(result);
+            }
+        }
+    }
+    return Ok(results);
+}
+
+
+
+// Return sizes from min to max that grow by about the given factor, so a
+// case that goes over budget does not overshoot it by much.
+pub fn size_ladder(min: usize, max: usize, growth: f64) -> Vec<usize> {
+    let mut sizes: Vec<usize> = vec![];
+    let mut size = min.max(1);
+    while size < max {
+        sizes.push(size);
+        size = ((size as f64 * growth).ceil() as usize).max(size + 1);
+    }
+    sizes.push(max);
+    return sizes;
+}
+
+
+
+// Return true if the case can run at the given size within the budget. The
+// case is tried once at each size of a ladder leading up to it, and gives up
+// as soon as the growth so far says the next size would go over budget, so
+// an exponential algorithm never starts a run it can't finish.
+pub fn fits_budget(case: &Case, size: usize, budget: Duration) -> Result<bool> {
+    let settings = Settings {
+        warmups: 0,
+        repetitions: 1,
+        budget,
+        ..Settings::default()
+    };
+    let ladder = size_ladder(1, size, 1.1);
+    let budget = budget.as_secs_f64();
+    let mut previous: Option<(usize, f64)> = None;
+    for i in 0..ladder.len() - 1 {
+        let result = bench(case, ladder[i], &settings)?;
+        let time = result.summary.median;
+        if result.over_budget {
+            return Ok(false);
+        }
+
+        // Assume the time keeps growing by the same factor per item.
+        if let Some((previous_size, previous_time)) = previous {
+            let growth = (time / previous_time.max(1e-9)).max(1.0);
+            let steps = (ladder[i + 1] - ladder[i]) as f64 / (ladder[i] - previous_size) as f64;
+            if time * growth.powf(steps) > budget {
+                return Ok(false);
+            }
+        }
+        previous = Some((ladder[i], time));
+    }
+    return Ok(true);
+}
+
+
+
+// Format seconds with a unit that suits their size.
+pub fn format_seconds(seconds: f64) -> String {
+    if seconds < 1e-6 {
+        return format!("{:.1}ns", seconds * 1e9);
+    } else if seconds < 1e-3 {
+        return format!("{:.2}µs", seconds * 1e6);
+    } else if seconds < 1.0 {
+        return format!("{:.2}ms", seconds * 1e3);
+    }
+    return format!("{:.3}s", seconds);
+}
+
+
+
+// Print the results as a table, grouped by size. The ratio column compares
+// each median with the fastest median at that size.
+pub fn print_table(results: &[BenchResult]) {
+    println!(
+        "{:<14} {:>9} {:>5} {:>11} {:>11} {:>11} {:>11} {:>11} {:>7}  counters",
+        "name", "size", "reps", "min", "median", "mean", "stddev", "p95", "ratio"
+    );
+    for result in results.iter() {
+        let fastest = results
+            .iter()
+            .filter(|other| other.size == result.size)
+            .map(|other| other.summary.median)
+            .fold(f64::INFINITY, f64::min);
+        let counters: Vec<String> = result
+            .counters
+            .iter()
+            .map(|(name, value)| format!("{name}={value:.1}"))
+            .collect();
+        println!(
+            "{:<14} {:>9} {:>5} {:>11} {:>11} {:>11} {:>11} {:>11} {:>7.2}  {}{}",
+            result.name,
+            result.size,
+            result.repetitions,
+            format_seconds(result.summary.min),
+            format_seconds(result.summary.median),
+            format_seconds(result.summary.mean),
+            format_seconds(result.summary.stddev),
+            format_seconds(result.summary.p95),
+            result.summary.median / fastest,
+            counters.join(" "),
+            if result.over_budget { " (over budget)" } else { "" }
+        );
+    }
+}
+
+
+
+// Write the results as CSV, one line per case and size.
+pub fn write_csv(results: &[BenchResult], out: impl Write) -> io::Result<()> {
+    let mut writer = Writer::new(Format::Csv, out);
+    for result in results.iter() {
+        writer.write(&result.record())?;
+    }
+    return Ok(());
+}
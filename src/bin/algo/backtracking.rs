@@ -0,0 +1,96 @@
+use algo::args::Args;
+use algo::error::Result;
+use algo::backtracking::{hanoi, knight_tour, nqueens};
+use algo::output::{Format, Record};
+use algo::prompt::Prompt;
+
+use crate::{emit, fail, metrics, output, write_trace};
+
+
+
+// algo queens N [--method 1|4]
+pub fn queens(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let num_rows = args.positional_or_ask(1, "n", Prompt::new("# Rows: ").min(1))?;
+    let method = args.value("method").unwrap_or("4");
+    let mut board = nqueens::make_board(num_rows);
+
+    let metrics = metrics(args)?;
+    let success = metrics.time("elapsed", || match method {
+        "1" => nqueens::place_queens_1(&mut board, 0, 0, &metrics),
+        "4" => nqueens::place_queens_4(&mut board, 0, &metrics),
+        _ => fail(&format!("Unknown method {method}")),
+    });
+    metrics.finish_progress();
+    let mut record = Record::new("queens")
+        .with("n", num_rows)
+        .with("method", method)
+        .with("success", success);
+    metrics.add_to(&mut record);
+    record.push("board", &board);
+    emit(&mut out, &record);
+    write_trace(args, &metrics)?;
+    return Ok(());
+}
+
+
+
+// algo knight --rows R --cols C [--row R0 --col C0] [--closed]
+pub fn knight(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let num_rows = args.or_ask("rows", Prompt::new("# Rows: ").min(1))?;
+    let num_cols = args.parsed_or("cols", num_rows)?;
+    let (row, col) = (args.parsed_or("row", 0)?, args.parsed_or("col", 0)?);
+    if num_cols == 0 || row >= num_rows || col >= num_cols {
+        fail("The start square must be on the board");
+    }
+    let closed = args.flag("closed");
+    let mut board = knight_tour::make_board(num_rows, num_cols);
+    board[row][col] = 0;
+
+    let metrics = metrics(args)?;
+    let success = metrics.time("elapsed", || {
+        knight_tour::find_tour(
+            &mut board,
+            &knight_tour::OFFSETS,
+            row as i32,
+            col as i32,
+            1,
+            closed,
+            &metrics,
+        )
+    });
+    metrics.finish_progress();
+    let mut record = Record::new("knight")
+        .with("rows", num_rows)
+        .with("cols", num_cols)
+        .with("row", row)
+        .with("col", col)
+        .with("closed", closed)
+        .with("success", success);
+    metrics.add_to(&mut record);
+    record.push("board", &board);
+    emit(&mut out, &record);
+    write_trace(args, &metrics)?;
+    return Ok(());
+}
+
+
+
+// algo hanoi N [--quiet]
+pub fn hanoi(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let num_disks = args.positional_or_ask(1, "n", Prompt::new("# Disks: ").max(63))?;
+    let draw = out.format == Format::Text && !args.flag("quiet");
+    let mut posts = hanoi::make_posts(num_disks);
+    if draw {
+        hanoi::draw_posts(&posts);
+    }
+    let metrics = metrics(args)?;
+    hanoi::move_disks(&mut posts, num_disks, 0, 1, 2, draw, &metrics);
+    let mut record = Record::new("hanoi").with("disks", num_disks);
+    metrics.add_to(&mut record);
+    emit(&mut out, &record);
+    write_trace(args, &metrics)?;
+    return Ok(());
+}
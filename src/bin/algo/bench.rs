@@ -0,0 +1,112 @@
+use std::fs::File;
+use std::time::Duration;
+
+use algo::args::Args;
+use algo::error::{parse, Result};
+use algo::metrics::Counter;
+use algo::bench::complexity::{estimate, print_estimate};
+use algo::bench::{compare, find_case, print_table, size_ladder, suite, write_csv, Settings};
+use algo::output::Format;
+use algo::rng::make_source;
+
+use crate::{emit, fail, output};
+
+
+
+// algo bench sort|presorted|reversed|organ-pipe|few-keys|search|knapsack
+//     [--algs A,B] [--sizes N,M | --min N --max M [--growth G]]
+//     [--warmups W] [--reps R] [--budget SECS] [--csv FILE]
+pub fn run(args: &Args) -> Result<()> {
+    let suite_name = args.positional(1).unwrap_or("sort");
+    let mut cases = suite(suite_name).unwrap_or_else(|| fail(&format!("Unknown suite {suite_name}")));
+    if let Some(names) = args.value("algs") {
+        let names: Vec<&str> = names.split(',').collect();
+        for name in names.iter() {
+            if !cases.iter().any(|case| case.name == *name) {
+                fail(&format!("Unknown {suite_name} algorithm {name}"));
+            }
+        }
+        cases.retain(|case| names.contains(&case.name.as_str()));
+    }
+
+    let results = compare(&cases, &sizes(args, suite_name)?, &settings(args)?)?;
+    let mut out = output(args);
+    if out.format == Format::Text {
+        print_table(&results);
+    } else {
+        for result in results.iter() {
+            emit(&mut out, &result.record());
+        }
+    }
+
+    if let Some(path) = args.value("csv") {
+        let file = File::create(path).unwrap_or_else(|e| fail(&format!("Cannot write {path}: {e}")));
+        write_csv(&results, file).unwrap_or_else(|e| fail(&format!("Cannot write {path}: {e}")));
+    }
+    return Ok(());
+}
+
+
+
+// algo complexity SUITE ALG [--metric time|COUNTER] [sizes and settings as for bench]
+pub fn complexity(args: &Args) -> Result<()> {
+    let suite_name = args.positional(1).unwrap_or_else(|| fail("Missing suite"));
+    let name = args.positional(2).unwrap_or_else(|| fail("Missing algorithm"));
+    let case = find_case(suite_name, name)
+        .unwrap_or_else(|| fail(&format!("Unknown {suite_name} algorithm {name}")));
+    let sizes = sizes(args, suite_name)?;
+    if sizes.len() < 3 {
+        fail("Need at least three sizes");
+    }
+
+    let metric = args.value("metric").unwrap_or("time");
+    if metric != "time" && !Counter::ALL.iter().any(|counter| counter.name() == metric) {
+        fail(&format!("Unknown metric {metric}"));
+    }
+    let estimate = estimate(&case, &sizes, metric, &settings(args)?)?;
+    let mut out = output(args);
+    if out.format == Format::Text {
+        print_estimate(&estimate);
+    } else {
+        for record in estimate.records() {
+            emit(&mut out, &record);
+        }
+    }
+    return Ok(());
+}
+
+
+
+// The sizes from --sizes, or a ladder from --min to --max.
+fn sizes(args: &Args, suite_name: &str) -> Result<Vec<usize>> {
+    if let Some(list) = args.value("sizes") {
+        return list.split(',').map(|size| parse(size, "size")).collect();
+    }
+
+    // Knapsack times can grow exponentially, so take small steps.
+    let growth = if suite_name == "knapsack" { 1.1 } else { 2.0 };
+    return Ok(size_ladder(
+        args.parsed_or("min", 10)?,
+        args.parsed_or("max", 1000)?,
+        args.parsed_or("growth", growth)?,
+    ));
+}
+
+
+
+// The run settings. Unknown generators are rejected here, before any
+// case runs.
+fn settings(args: &Args) -> Result<Settings> {
+    let defaults = Settings::default();
+    let rng = args.value("rng").unwrap_or(&defaults.rng).to_string();
+    if make_source(&rng, 0).is_none() {
+        fail(&format!("Unknown generator {rng}"));
+    }
+    return Ok(Settings {
+        warmups: args.parsed_or("warmups", defaults.warmups)?,
+        repetitions: args.parsed_or("reps", defaults.repetitions)?,
+        seed: args.parsed_or("seed", defaults.seed)?,
+        rng,
+        budget: Duration::from_secs_f64(args.parsed_or("budget", defaults.budget.as_secs_f64())?),
+    });
+}
@@ -0,0 +1,113 @@
+use algo::args::Args;
+use algo::error::Result;
+use algo::knapsack::{
+    branch_and_bound, dynamic_programming, exhaustive_search, make_items, print_items,
+    print_parameters, read_instance, rods_technique, rods_technique_sorted, solution_record,
+    sum_weights, time_algorithm, write_instance, Fixed, Item, Number, Solver,
+};
+use algo::output::Format;
+use algo::prompt::Prompt;
+
+use crate::{emit, fail, metrics, output, write_trace};
+
+// The dp_* programs always start from this seed.
+const SEED: u32 = 1337;
+
+
+
+// algo knapsack --solver NAME [--file FILE [--numbers TYPE] | --n N ...] [--save FILE]
+pub fn run(args: &Args) -> Result<()> {
+    let numbers = args.value("numbers").unwrap_or("i32");
+    if numbers == "i32" {
+        // Read the instance, or make a random one.
+        let (items, allowed_weight) = match args.value("file") {
+            Some(path) => read::<i32>(path),
+            None => {
+                let mut prng = args.source(Some(SEED))?;
+                let items = make_items(
+                    &mut prng,
+                    args.or_ask("n", Prompt::new("# Items: ").min(0))?,
+                    args.parsed_or("min-value", 1)?,
+                    args.parsed_or("max-value", 10)?,
+                    args.parsed_or("min-weight", 4)?,
+                    args.parsed_or("max-weight", 10)?,
+                );
+                let allowed_weight = sum_weights(&items, true)? / 2;
+                (items, allowed_weight)
+            }
+        };
+        return solve(args, items, allowed_weight);
+    }
+
+    // Random instances are always i32, so other numbers come from a file.
+    let path = match args.value("file") {
+        Some(path) => path,
+        None => fail(&format!("--numbers {numbers} needs --file")),
+    };
+    return match numbers {
+        "i64" => solve_file::<i64>(args, path),
+        "u64" => solve_file::<u64>(args, path),
+        "u128" => solve_file::<u128>(args, path),
+        // Decimals with two or three places, such as prices or kilograms.
+        "fixed2" => solve_file::<Fixed<2>>(args, path),
+        "fixed3" => solve_file::<Fixed<3>>(args, path),
+        _ => fail(&format!("Unknown number type {numbers}")),
+    };
+}
+
+
+
+// Read an instance file whose values and weights are all of type N.
+fn read<N: Number>(path: &str) -> (Vec<Item<N, N>>, N) {
+    return read_instance(path).unwrap_or_else(|e| fail(&format!("Cannot read {path}: {e}")));
+}
+
+
+
+fn solve_file<N: Number>(args: &Args, path: &str) -> Result<()> {
+    let (items, allowed_weight) = read::<N>(path);
+    return solve(args, items, allowed_weight);
+}
+
+
+
+// Run the chosen solvers on the instance.
+fn solve<N: Number>(args: &Args, mut items: Vec<Item<N, N>>, allowed_weight: N) -> Result<()> {
+    let solvers: [(&str, &str, &Solver<N, N>); 5] = [
+        ("exhaustive", "Exhaustive Search", &exhaustive_search),
+        ("bnb", "Branch and Bound Search", &branch_and_bound),
+        ("rods", "Rod's Technique", &rods_technique),
+        ("rods-sorted", "Rod's Technique Sorted", &rods_technique_sorted),
+        ("dp", "Dynamic programming", &dynamic_programming),
+    ];
+    let solver = args.value("solver").unwrap_or("all");
+    if solver != "all" && !solvers.iter().any(|(name, _, _)| *name == solver) {
+        fail(&format!("Unknown solver {solver}"));
+    }
+
+    let mut out = output(args);
+    let text = out.format == Format::Text;
+    if let Some(path) = args.value("save") {
+        write_instance(path, &items, allowed_weight)
+            .unwrap_or_else(|e| fail(&format!("Cannot write {path}: {e}")));
+    }
+
+    if text {
+        print_parameters(&items, allowed_weight);
+    }
+    for (name, title, alg) in solvers {
+        if solver == "all" || solver == name {
+            let metrics = metrics(args)?;
+            let (solution, total_value) = time_algorithm(alg, &mut items, allowed_weight, &metrics)?;
+            metrics.finish_progress();
+            if text {
+                println!("*** {title} ***");
+                print_items(&solution, false);
+            }
+            let record = solution_record(name, &solution, total_value, &metrics)?;
+            emit(&mut out, &record);
+            write_trace(args, &metrics)?;
+        }
+    }
+    return Ok(());
+}
@@ -0,0 +1,232 @@
+// The algo command-line front end. Every value can be given as a flag;
+// anything left out is prompted for, as the single-purpose programs do.
+
+mod backtracking;
+mod bench;
+mod knapsack;
+mod number;
+mod rsa;
+mod search;
+mod sort;
+
+use std::env;
+use std::fs;
+use std::io;
+use std::process;
+use std::time::Duration;
+
+use algo::args::Args;
+use algo::budget::Budget;
+use algo::error::{Error, Result};
+use algo::metrics::Metrics;
+use algo::output::{Format, Record, Writer};
+use algo::progress::{Progress, ProgressBar, DEFAULT_INTERVAL};
+use algo::trace::{Tracer, DEFAULT_MAX_NODES};
+
+const USAGE: &str = "\
+Usage: algo <command> [options]
+
+Commands:
+  sort --alg bubble|insertion|quick|heap|counting|radix|msd --n N --max M
+       [--show K] [--partition lomuto|dijkstra|bentley-mcilroy] [--digits 8|11]
+       [--progress]
+  search linear|binary --n N --max M [--target T]
+  factor N [--sieve MAX] [--progress]
+  primes --digits D [--count C]
+  sieve MAX [--show] [--progress]
+  gcd A B [trace options]
+  exp NUM POW [--mod M]
+  fib N [--method naive|memo] [trace options]
+  factorial N
+  rsa keygen | rsa encrypt --n N --e E --m M | rsa decrypt --n N --d D --c C
+  knapsack --solver exhaustive|bnb|rods|rods-sorted|dp|all
+           [--file FILE [--numbers i32|i64|u64|u128|fixed2|fixed3]
+            | --n N --min-value V --max-value V --min-weight W --max-weight W]
+           [--save FILE] [--progress] [budget options] [trace options]
+  queens N [--method 1|4] [--progress] [budget options] [trace options]
+  knight --rows R --cols C [--row R0 --col C0] [--closed] [--progress]
+         [budget options] [trace options]
+  hanoi N [--quiet] [trace options]
+  bench sort|presorted|reversed|organ-pipe|few-keys|search|knapsack
+        [--algs A,B] [--sizes N,M | --min N --max M [--growth G]]
+        [--warmups W] [--reps R] [--budget SECS] [--csv FILE]
+  complexity SUITE ALG [--metric time|COUNTER] [sizes and settings as for bench]
+
+Every command takes --format text|json|csv. Commands that make random
+data also take --seed S and --rng lcg|splitmix64|xoshiro256**|pcg32.
+
+Values left off the command line are prompted for. Answer q to quit,
+!! or !N to repeat an earlier answer, or history to list them.
+  --script FILE    read the answers from FILE instead of the keyboard
+  --history FILE   append the accepted answers to FILE, for a later --script
+
+--progress draws a progress bar on stderr while the work runs, redrawn at
+most every --progress-interval SECS (0.1 by default).
+
+Budget options stop a search early with the best solution found so far;
+the record's stopped field says why:
+  --time-limit SECS  stop after SECS seconds
+  --max-nodes N      stop after N calls
+
+Trace options record the call tree of the recursive algorithms:
+  --trace text|dot [--trace-depth D] [--trace-nodes N] [--trace-file FILE]";
+
+
+
+fn main() {
+    let words: Vec<String> = env::args().skip(1).collect();
+    let args = Args::parse(words.clone(), &flags(words.first().map(|word| word.as_str())));
+    let result = match args.positional(0) {
+        Some("sort") => sort::run(&args),
+        Some("search") => search::run(&args),
+        Some("factor") => number::factor(&args),
+        Some("primes") => number::primes(&args),
+        Some("sieve") => number::sieve(&args),
+        Some("gcd") => number::gcd(&args),
+        Some("exp") => number::exp(&args),
+        Some("fib") => number::fib(&args),
+        Some("factorial") => number::factorial(&args),
+        Some("rsa") => rsa::run(&args),
+        Some("knapsack") => knapsack::run(&args),
+        Some("queens") => backtracking::queens(&args),
+        Some("knight") => backtracking::knight(&args),
+        Some("hanoi") => backtracking::hanoi(&args),
+        Some("bench") => bench::run(&args),
+        Some("complexity") => bench::complexity(&args),
+        Some("help") | None => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some(command) => fail(&format!("Unknown command {command}")),
+    };
+    if let Err(error) = result {
+        exit_with(error);
+    }
+}
+
+
+
+// The options the command takes without a value, so that the word after
+// one is not taken as its value. The command is expected to come first.
+fn flags(command: Option<&str>) -> Vec<&'static str> {
+    let mut flags = vec!["progress"];
+    match command {
+        Some("sieve") => flags.push("show"),
+        Some("knight") => flags.push("closed"),
+        Some("hanoi") => flags.push("quiet"),
+        _ => {}
+    }
+    return flags;
+}
+
+
+
+// Report a command's error and exit. Input that cannot be parsed also
+// gets the usage text, and quitting at a prompt exits quietly.
+fn exit_with(error: Error) -> ! {
+    match error {
+        Error::ParseError { .. } => fail(&format!("Error: {error}")),
+        Error::EndOfInput => process::exit(0),
+        _ => {}
+    }
+    eprintln!("Error: {error}");
+    process::exit(1);
+}
+
+
+
+// Print the message and the usage text, then exit.
+pub fn fail(message: &str) -> ! {
+    eprintln!("{message}\n");
+    eprintln!("{USAGE}");
+    process::exit(2);
+}
+
+
+
+// Return the --format option, text by default.
+pub fn format(args: &Args) -> Format {
+    let name = args.value("format").unwrap_or("text");
+    return Format::from_name(name).unwrap_or_else(|| fail(&format!("Unknown format {name}")));
+}
+
+
+
+// Build the writer the command's records go to.
+pub fn output(args: &Args) -> Writer<io::Stdout> {
+    return Writer::new(format(args), io::stdout());
+}
+
+
+
+// Build the Metrics for a run, recording the call tree if --trace was given
+// and limiting the search if --time-limit or --max-nodes was.
+pub fn metrics(args: &Args) -> Result<Metrics> {
+    let mut metrics = match args.value("trace") {
+        Some(_) => Metrics::with_tracer(Tracer::new(args.parsed_or("trace-nodes", DEFAULT_MAX_NODES)?)),
+        None => Metrics::new(),
+    };
+    let time_limit = args.parsed::<f64>("time-limit")?;
+    let max_nodes = args.parsed::<u64>("max-nodes")?;
+    if time_limit.is_some() || max_nodes.is_some() {
+        let mut budget = Budget::unlimited();
+        if let Some(secs) = time_limit {
+            let limit = Duration::try_from_secs_f64(secs)
+                .map_err(|_| Error::InvalidArgument(format!("--time-limit {secs} is not a duration")))?;
+            budget = budget.with_time_limit(limit);
+        }
+        if let Some(max_nodes) = max_nodes {
+            budget = budget.with_max_nodes(max_nodes);
+        }
+        metrics = metrics.with_budget(budget);
+    }
+    return with_progress(args, metrics);
+}
+
+
+
+// Give the Metrics a progress bar if --progress was given.
+pub fn with_progress(args: &Args, metrics: Metrics) -> Result<Metrics> {
+    if !args.flag("progress") {
+        return Ok(metrics);
+    }
+    let interval = match args.parsed::<f64>("progress-interval")? {
+        Some(secs) => Duration::try_from_secs_f64(secs)
+            .map_err(|_| Error::InvalidArgument(format!("--progress-interval {secs} is not a duration")))?,
+        None => DEFAULT_INTERVAL,
+    };
+    return Ok(metrics.with_progress(Progress::new(ProgressBar::stderr()).with_interval(interval)));
+}
+
+
+
+// Write the call tree recorded by --trace to --trace-file, or else to stdout
+// in text mode and stderr otherwise, so it does not mix with the records.
+pub fn write_trace(args: &Args, metrics: &Metrics) -> Result<()> {
+    let tracer = match metrics.take_tracer() {
+        Some(tracer) => tracer,
+        None => return Ok(()),
+    };
+    let max_depth = args.parsed::<usize>("trace-depth")?;
+    let tree = match args.value("trace") {
+        Some("dot") => tracer.to_dot(max_depth),
+        Some("text") | Some("true") => tracer.to_text(max_depth),
+        Some(name) => fail(&format!("Unknown trace format {name}")),
+        None => return Ok(()),
+    };
+    match args.value("trace-file") {
+        Some(path) => fs::write(path, tree).unwrap_or_else(|e| fail(&format!("Cannot write {path}: {e}"))),
+        None if format(args) == Format::Text => print!("{tree}"),
+        None => eprint!("{tree}"),
+    }
+    return Ok(());
+}
+
+
+
+// Write a record, giving up quietly if stdout has been closed.
+pub fn emit(out: &mut Writer<io::Stdout>, record: &Record) {
+    if out.write(record).is_err() {
+        process::exit(1);
+    }
+}
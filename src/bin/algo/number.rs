@@ -0,0 +1,184 @@
+use std::time::Instant;
+
+use algo::args::Args;
+use algo::error::Result;
+use algo::number_theory::{
+    self, fast_exp, fast_exp_mod, fibonacci_on_the_fly_traced, fibonacci_traced, find_factors_sieve,
+    find_factors_with_progress, find_prime, gcd_traced, lcm_with_gcd, multiply_vector, print_sieve,
+    sieve_of_eratosthenes_with_progress, sieve_to_primes,
+};
+use algo::metrics::Metrics;
+use algo::output::{Format, Record};
+use algo::prompt::Prompt;
+
+use crate::{emit, fail, metrics, output, with_progress, write_trace};
+
+const NUM_TESTS: i64 = 20;
+
+// The sieve takes one byte per number.
+const MAX_SIEVE: i64 = 1_000_000_000;
+
+// fibonacci(93) does not fit in an i64.
+const MAX_FIBONACCI: i64 = 92;
+
+
+
+// algo factor N [--sieve MAX] [--progress]
+pub fn factor(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let num = args.positional_or_ask(1, "num", Prompt::new("Num: ").min(1))?;
+    let metrics = with_progress(args, Metrics::new())?;
+
+    // Find the factors the slow way.
+    let start = Instant::now();
+    let factors = find_factors_with_progress(num, &metrics)?;
+    let duration = start.elapsed();
+    metrics.finish_progress();
+    let record = Record::new("factor")
+        .with("method", "trial")
+        .with("num", num)
+        .with("factors", &factors)
+        .with("product", multiply_vector(&factors))
+        .with("elapsed", duration);
+    emit(&mut out, &record);
+
+    // Use Eratosthenes' sieve to find the factors.
+    if let Some(max) = args.parsed::<usize>("sieve")? {
+        let primes = sieve_to_primes(&sieve_of_eratosthenes_with_progress(max, &metrics));
+        metrics.finish_progress();
+        let start = Instant::now();
+        let factors = find_factors_sieve(&primes, num)?;
+        let duration = start.elapsed();
+        let record = Record::new("factor")
+            .with("method", "sieve")
+            .with("num", num)
+            .with("factors", &factors)
+            .with("product", multiply_vector(&factors))
+            .with("elapsed", duration);
+        emit(&mut out, &record);
+    }
+    return Ok(());
+}
+
+
+
+// algo primes --digits D [--count C]
+pub fn primes(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let mut prng = args.source(None)?;
+    let num_digits = args.or_ask("digits", Prompt::new("# Digits (max 9): ").range(1, 9))?;
+
+    // Calculate minimum and maximum values.
+    let mut min = 10i64.pow((num_digits - 1) as u32);
+    let max = 10 * min;
+    if min == 1 {
+        min = 2; // 1 is not prime.
+    }
+
+    let probability = (1.0 - 0.5_f64.powf(NUM_TESTS as f64)) * 100.0;
+    for _ in 0..args.parsed_or("count", 1)? {
+        let record = Record::new("prime")
+            .with("digits", num_digits)
+            .with("prime", find_prime(&mut prng, min as i32, max as i32, NUM_TESTS)?)
+            .with("probability", probability);
+        emit(&mut out, &record);
+    }
+    return Ok(());
+}
+
+
+
+// algo sieve MAX [--show] [--progress]
+pub fn sieve(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let max = args.positional_or_ask(1, "max", Prompt::new("Max: ").range(0, MAX_SIEVE))?;
+    let metrics = with_progress(args, Metrics::new())?;
+    let sieve = sieve_of_eratosthenes_with_progress(max as usize, &metrics);
+    metrics.finish_progress();
+    let primes = sieve_to_primes(&sieve);
+    let mut record = Record::new("sieve").with("max", max).with("count", primes.len());
+    if args.flag("show") {
+        if out.format == Format::Text {
+            print_sieve(&sieve);
+        } else {
+            record.push("primes", &primes);
+        }
+    }
+    emit(&mut out, &record);
+    return Ok(());
+}
+
+
+
+// algo gcd A B
+pub fn gcd(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let a = args.positional_or_ask(1, "a", Prompt::new("A: "))?;
+    let b = args.positional_or_ask(2, "b", Prompt::new("B: "))?;
+    let metrics = metrics(args)?;
+    let gcdv = gcd_traced(a, b, &metrics)?;
+    let record = Record::new("gcd")
+        .with("a", a)
+        .with("b", b)
+        .with("gcd", gcdv)
+        .with("lcm", lcm_with_gcd(a, b, gcdv)?);
+    emit(&mut out, &record);
+    write_trace(args, &metrics)?;
+    return Ok(());
+}
+
+
+
+// algo exp NUM POW [--mod M]
+pub fn exp(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let num = args.positional_or_ask(1, "num", Prompt::new("Num: "))?;
+    let pow = args.positional_or_ask(2, "pow", Prompt::new("Pow: ").min(0))?;
+    let mut record = Record::new("exp").with("num", num).with("pow", pow);
+    match args.parsed::<i64>("mod")? {
+        Some(modulus) => {
+            record.push("mod", modulus);
+            record.push("result", fast_exp_mod(num, pow, modulus)?);
+        }
+        None => record.push("result", fast_exp(num, pow)?),
+    }
+    emit(&mut out, &record);
+    return Ok(());
+}
+
+
+
+// algo fib N [--method naive|memo]
+pub fn fib(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let n = args.positional_or_ask(1, "n", Prompt::new("N: ").range(0, MAX_FIBONACCI))?;
+    let method = args.value("method").unwrap_or("memo");
+    let metrics = metrics(args)?;
+    let start = Instant::now();
+    let value = match method {
+        "naive" => fibonacci_traced(n, &metrics),
+        "memo" => fibonacci_on_the_fly_traced(&mut vec![0, 1], n, &metrics),
+        _ => fail(&format!("Unknown method {method}")),
+    };
+    let record = Record::new("fibonacci")
+        .with("method", method)
+        .with("n", n)
+        .with("value", value)
+        .with("elapsed", start.elapsed());
+    emit(&mut out, &record);
+    write_trace(args, &metrics)?;
+    return Ok(());
+}
+
+
+
+// algo factorial N
+pub fn factorial(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let n = args.positional_or_ask(1, "n", Prompt::new("N: ").min(0))?;
+    let record = Record::new("factorial")
+        .with("n", n)
+        .with("value", number_theory::factorial(n)?);
+    emit(&mut out, &record);
+    return Ok(());
+}
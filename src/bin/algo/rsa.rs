@@ -0,0 +1,51 @@
+use algo::args::Args;
+use algo::error::Result;
+use algo::number_theory::fast_exp_mod;
+use algo::output::Record;
+use algo::prompt::Prompt;
+use algo::rsa::generate_keys;
+
+use crate::{emit, fail, output};
+
+
+
+// algo rsa keygen | encrypt --n N --e E --m M | decrypt --n N --d D --c C
+pub fn run(args: &Args) -> Result<()> {
+    let mut out = output(args);
+    let record = match args.positional(1).unwrap_or("keygen") {
+        "keygen" => {
+            let mut prng = args.source(None)?;
+            let keys = generate_keys(&mut prng)?;
+            Record::new("rsa_keys")
+                .with("n", keys.n)
+                .with("e", keys.e)
+                .with("p", keys.p)
+                .with("q", keys.q)
+                .with("lambda_n", keys.lambda_n)
+                .with("d", keys.d)
+        }
+        "encrypt" => {
+            let n = args.or_ask("n", Prompt::new("Public key modulus (n): ").min(1))?;
+            let e = args.or_ask("e", Prompt::new("Public key exponent (e): ").min(0))?;
+            let m = args.or_ask("m", Prompt::new("Message: "))?;
+            Record::new("rsa_encrypt")
+                .with("n", n)
+                .with("e", e)
+                .with("message", m)
+                .with("ciphertext", fast_exp_mod(m, e, n)?)
+        }
+        "decrypt" => {
+            let n = args.or_ask("n", Prompt::new("Public key modulus (n): ").min(1))?;
+            let d = args.or_ask("d", Prompt::new("Private exponent (d): ").min(0))?;
+            let c = args.or_ask("c", Prompt::new("Ciphertext: "))?;
+            Record::new("rsa_decrypt")
+                .with("n", n)
+                .with("d", d)
+                .with("ciphertext", c)
+                .with("message", fast_exp_mod(c, d, n)?)
+        }
+        action => fail(&format!("Unknown rsa action {action}")),
+    };
+    emit(&mut out, &record);
+    return Ok(());
+}
@@ -0,0 +1,72 @@
+use algo::args::Args;
+use algo::error::{Error, Result};
+use algo::io::print_vec;
+use algo::metrics::Metrics;
+use algo::output::{Format, Record, Writer};
+use algo::prompt::Prompt;
+use algo::searching::{binary_search, linear_search};
+use algo::sorting::{make_random_vec, quicksort};
+
+use crate::{emit, fail, output};
+
+type Search = fn(&Vec<i32>, i32, &Metrics) -> Result<i32>;
+
+
+
+// algo search linear|binary --n N --max M [--target T]
+pub fn run(args: &Args) -> Result<()> {
+    let method = args.positional(1).or(args.value("method")).unwrap_or("binary").to_string();
+    let search: Search = match method.as_str() {
+        "linear" => |vec, target, metrics| Ok(linear_search(vec, target, metrics)),
+        "binary" => binary_search,
+        _ => fail(&format!("Unknown search method {method}")),
+    };
+
+    let mut out = output(args);
+    let mut prng = args.source(None)?;
+    let num_items = args.or_ask("n", Prompt::new("# Items: ").min(1))?;
+    let max_value = args.or_ask("max", Prompt::new("Max: ").min(1))?;
+    let mut vec = make_random_vec(&mut prng, num_items, max_value);
+    if method == "binary" {
+        quicksort(&mut vec, &Metrics::new());
+    }
+    if out.format == Format::Text {
+        print_vec(&vec, args.parsed_or("show", 40)?);
+        println!();
+    }
+
+    // Search once for --target, or keep prompting for targets.
+    if let Some(target) = args.parsed::<i32>("target")? {
+        return report(&mut out, &method, &vec, target, search);
+    }
+    loop {
+        let target = match args.ask(&Prompt::new("Target (-1 to quit): ")) {
+            Err(Error::EndOfInput) | Ok(-1) => break,
+            target => target?,
+        };
+        report(&mut out, &method, &vec, target, search)?;
+    }
+    return Ok(());
+}
+
+
+
+fn report(
+    out: &mut Writer<std::io::Stdout>,
+    method: &str,
+    vec: &Vec<i32>,
+    target: i32,
+    search: Search,
+) -> Result<()> {
+    let metrics = Metrics::new();
+    let index = search(vec, target, &metrics)?;
+    let found = index >= 0 && index < vec.len() as i32;
+    let mut record = Record::new("search")
+        .with("method", method)
+        .with("target", target)
+        .with("found", found)
+        .with("index", if found { index } else { -1 });
+    metrics.add_to(&mut record);
+    emit(out, &record);
+    return Ok(());
+}
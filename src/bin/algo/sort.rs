@@ -0,0 +1,103 @@
+use algo::args::Args;
+use algo::error::Result;
+use algo::io::print_vec;
+use algo::metrics::Metrics;
+use algo::output::{Format, Record};
+use algo::prompt::Prompt;
+use algo::sorting::{
+    bubble_sort, check_sorted, check_sorted_by_key, counting_sort, heapsort, insertion_sort, is_sorted,
+    is_sorted_by_key, lsd_radix_sort, make_random_customers, make_random_vec, msd_radix_sort_by_key, quicksort_with,
+    Digits, Partition,
+};
+
+use crate::{emit, fail, output, with_progress};
+
+
+
+// algo sort --alg bubble|insertion|quick|heap|counting|radix|msd --n N --max M
+//     [--show K] [--partition lomuto|dijkstra|bentley-mcilroy] [--digits 8|11]
+pub fn run(args: &Args) -> Result<()> {
+    let alg = args.value("alg").unwrap_or("quick").to_string();
+    if !["bubble", "insertion", "quick", "heap", "counting", "radix", "msd"].contains(&alg.as_str()) {
+        fail(&format!("Unknown sort algorithm {alg}"));
+    }
+    let partition_name = args.value("partition").unwrap_or("lomuto");
+    let partition = Partition::ALL
+        .into_iter()
+        .find(|partition| partition.name() == partition_name)
+        .unwrap_or_else(|| fail(&format!("Unknown partition {partition_name}")));
+    let digits_name = args.value("digits").unwrap_or("8");
+    let digits = Digits::ALL
+        .into_iter()
+        .find(|digits| digits.name() == digits_name)
+        .unwrap_or_else(|| fail(&format!("Unknown digit size {digits_name}")));
+    let mut out = output(args);
+    let text = out.format == Format::Text;
+    let mut prng = args.source(None)?;
+    let num_items = args.or_ask("n", Prompt::new("Enter number of items:").min(0))?;
+    let max_value = args.or_ask("max", Prompt::new("Enter non-inclusive maximum item value:").min(1))?;
+    let show = args.parsed_or("show", 20)?;
+
+    let metrics = with_progress(args, Metrics::new())?;
+    let sorted;
+    if alg == "counting" {
+        let vec = make_random_customers(&mut prng, num_items, max_value);
+        let rvec = metrics.time("elapsed", || counting_sort(&vec, max_value))?;
+        sorted = is_sorted_by_key(&rvec, |customer| customer.num_purchases);
+        if text {
+            print_vec(&vec, show);
+            print_vec(&rvec, show);
+            check_sorted_by_key(&rvec, |customer| customer.num_purchases);
+        }
+    } else if alg == "msd" {
+        // The customers' ids, C0, C1, ..., are out of order as strings.
+        let mut vec = make_random_customers(&mut prng, num_items, max_value);
+        if text {
+            print_vec(&vec, show);
+        }
+        metrics.time("elapsed", || {
+            return msd_radix_sort_by_key(&mut vec, |customer| customer.id.as_bytes(), &metrics);
+        })?;
+        sorted = is_sorted_by_key(&vec, |customer| customer.id.clone());
+        if text {
+            print_vec(&vec, show);
+            check_sorted_by_key(&vec, |customer| customer.id.clone());
+        }
+    } else {
+        let mut vec = make_random_vec(&mut prng, num_items, max_value);
+        if text {
+            print_vec(&vec, show);
+        }
+        metrics.time("elapsed", || -> Result<()> {
+            match alg.as_str() {
+                "bubble" => bubble_sort(&mut vec, &metrics),
+                "insertion" => insertion_sort(&mut vec, &metrics),
+                "heap" => heapsort(&mut vec, &metrics),
+                "radix" => lsd_radix_sort(&mut vec, digits, &metrics)?,
+                _ => quicksort_with(&mut vec, partition, &metrics),
+            }
+            return Ok(());
+        })?;
+        metrics.finish_progress();
+        sorted = is_sorted(&vec);
+        if text {
+            print_vec(&vec, show);
+            check_sorted(&vec);
+        }
+    }
+
+    let mut record = Record::new("sort")
+        .with("alg", alg.as_str())
+        .with("n", num_items)
+        .with("max", max_value)
+        .with("sorted", sorted);
+    if alg == "quick" {
+        record.push("partition", partition.name());
+    }
+    if alg == "radix" {
+        record.push("digits", digits.bits() as i64);
+    }
+    metrics.add_to(&mut record);
+    emit(&mut out, &record);
+    return Ok(());
+}
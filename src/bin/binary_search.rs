@@ -0,0 +1,49 @@
+use algo::args::Args;
+use algo::error::{exit_on_error, Result};
+use algo::io::print_vec;
+use algo::metrics::{Counter, Metrics};
+use algo::prompt::Prompt;
+use algo::searching::binary_search;
+use algo::sorting::{make_random_vec, quicksort};
+
+
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args = Args::from_env(&[]);
+
+    // Prepare a random source, seeded from --seed or the clock.
+    let mut prng = args.source(None)?;
+
+    let num_items = args.ask(&Prompt::new("# Items: ").min(1))?;
+    let max_value = args.ask(&Prompt::new("Max: ").min(1))?;
+    let mut vec = make_random_vec(&mut prng, num_items, max_value);
+    quicksort(&mut vec, &Metrics::new());
+    print_vec(&vec, 40);
+    println!();
+
+    loop {
+        let target: i32 = args.ask(&Prompt::new("Target (-1 to quit): "))?;
+        if target == -1 {
+            break;
+        }
+
+        let metrics = Metrics::new();
+        let index = binary_search(&vec, target, &metrics)?;
+        let num_tests = metrics.get(Counter::Probes);
+        if index < 0 || index >= vec.len() as i32 {
+            println!("Target {} not found, {} tests", target, num_tests);
+        } else {
+            println!(
+                "numbers[{}] = {}, {} tests",
+                index, vec[index as usize], num_tests
+            );
+        }
+    }
+    return Ok(());
+}
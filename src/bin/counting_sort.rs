@@ -0,0 +1,29 @@
+use algo::args::Args;
+use algo::error::{exit_on_error, Result};
+use algo::io::print_vec;
+use algo::prompt::Prompt;
+use algo::sorting::{check_sorted_by_key, counting_sort, make_random_customers};
+
+
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args = Args::from_env(&[]);
+
+    // Prepare a random source, seeded from --seed or the clock.
+    let mut prng = args.source(None)?;
+
+    let num_items = args.ask(&Prompt::new("Enter number of items:").min(0))?;
+    let max_value = args.ask(&Prompt::new("Enter non-inclusive maximum item value:").min(1))?;
+    let vec = make_random_customers(&mut prng, num_items, max_value);
+    print_vec(&vec, 20);
+    let rvec = counting_sort(&vec, max_value)?;
+    print_vec(&rvec, 20);
+    check_sorted_by_key(&rvec, |customer| customer.num_purchases);
+    return Ok(());
+}
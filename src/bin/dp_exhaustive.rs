@@ -0,0 +1,52 @@
+// 1. Exhaustive search
+
+use std::time::Duration;
+
+use algo::args::Args;
+use algo::bench::{find_case, fits_budget};
+use algo::error::{exit_on_error, Result};
+use algo::knapsack::{exhaustive_search, make_items, print_parameters, run_algorithm, sum_weights};
+
+const NUM_ITEMS: i32 = 20; // A reasonable value for exhaustive search.
+
+const SEED: u32 = 1337;
+
+// Skip any solver that can't reach NUM_ITEMS items within this time.
+const BUDGET: Duration = Duration::from_secs(5);
+
+const MIN_VALUE: i32 = 1;
+const MAX_VALUE: i32 = 10;
+const MIN_WEIGHT: i32 = 4;
+const MAX_WEIGHT: i32 = 10;
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args = Args::from_env(&[]);
+
+    // Prepare a random source using the same seed each time unless --seed is given.
+    let mut prng = args.source(Some(SEED))?;
+
+    // Make some random items.
+    let mut items = make_items(
+        &mut prng, NUM_ITEMS, MIN_VALUE, MAX_VALUE, MIN_WEIGHT, MAX_WEIGHT,
+    );
+    let allowed_weight = sum_weights(&items, true)? / 2;
+
+    // Display basic parameters.
+    print_parameters(&items, allowed_weight);
+
+    // Exhaustive search
+    if !fits_budget(&find_case("knapsack", "exhaustive").unwrap(), NUM_ITEMS as usize, BUDGET)? {
+        // Only run exhaustive search if it can finish within the budget.
+        println!("Too many items for exhaustive search\n");
+    } else {
+        println!("*** Exhaustive Search ***");
+        run_algorithm(&exhaustive_search, &mut items, allowed_weight)?;
+    }
+    return Ok(());
+}
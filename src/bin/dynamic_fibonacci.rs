@@ -0,0 +1,35 @@
+use algo::args::Args;
+use algo::error::{exit_on_error, Result};
+use algo::number_theory::fibonacci_on_the_fly;
+use algo::prompt::Prompt;
+
+
+
+// fibonacci(93) does not fit in an i64.
+const MAX_N: i64 = 92;
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    // Create a vector for fill-on-the-fly.
+    let mut fill_on_the_fly_values: Vec<i64> = vec![0, 1];
+
+    let args = Args::from_env(&[]);
+    loop {
+        // Prompt the user for n.
+        let n = args.ask(&Prompt::new("N: ").range(-1, MAX_N))?;
+
+        if n < 0 {
+            break;
+        }
+
+        // Calculate the Fibonacci number.
+        println!("On the fly: {}", fibonacci_on_the_fly(&mut fill_on_the_fly_values, n));
+        println!();
+    }
+    return Ok(());
+}
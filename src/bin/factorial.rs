@@ -0,0 +1,12 @@
+use algo::number_theory::factorial;
+
+
+
+fn main() {
+    for n in 0..22 {
+        match factorial(n) {
+            Ok(value) => println!("{}! = {}", n, value),
+            Err(error) => println!("{}! cannot be calculated: {}", n, error),
+        }
+    }
+}
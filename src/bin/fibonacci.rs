@@ -0,0 +1,33 @@
+use algo::args::Args;
+use algo::error::{exit_on_error, Result};
+use algo::number_theory::fibonacci;
+use algo::prompt::Prompt;
+
+
+
+// fibonacci(93) does not fit in an i64.
+const MAX_N: i64 = 92;
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args = Args::from_env(&[]);
+    println!("Enter -1 to exit\n");
+    loop {
+        // Prompt the user for n.
+        let n = args.ask(&Prompt::new("N: ").range(-1, MAX_N))?;
+
+        // If n < 0, break out of the loop.
+        if n < 0 {
+            break;
+        }
+
+        // Calculate the Fibonacci number.
+        println!("fibonacci({}) = {}\n", n, fibonacci(n));
+    }
+    return Ok(());
+}
@@ -0,0 +1,38 @@
+use std::time::Instant;
+
+use algo::backtracking::knight_tour::{dump_board, find_tour, make_board, OFFSETS};
+use algo::metrics::{Counter, Metrics};
+
+// The board dimensions.
+const NUM_ROWS: usize = 8;
+const NUM_COLS: usize = NUM_ROWS;
+
+// Whether we want an open or closed tour.
+const REQUIRE_CLOSED_TOUR: bool = false;
+
+
+
+fn main() {
+    // Create a NUM_ROWS x NUM_COLS board with all entries initialized to UNVISITED.
+    let mut board = make_board(NUM_ROWS, NUM_COLS);
+
+    // Start at board[0][0].
+    board[0][0] = 0;
+
+    let metrics = Metrics::new();
+
+    // Try to find a tour.
+    let start = Instant::now();
+    let success = find_tour(&mut board, &OFFSETS, 0, 0, 1, REQUIRE_CLOSED_TOUR, &metrics);
+    let duration = start.elapsed();
+    println!("Time: {:?}", duration);
+    println!("Calls: {:?}", metrics.get(Counter::Calls));
+
+    if success {
+        println!("Success!");
+    } else {
+        println!("Could not find a tour.");
+    }
+
+    dump_board(&board);
+}
@@ -0,0 +1,30 @@
+use std::time::Instant;
+
+use algo::backtracking::nqueens::{dump_board, make_board, place_queens_1};
+use algo::metrics::Metrics;
+
+// The board dimensions.
+const NUM_ROWS: usize = 5;
+
+
+
+fn main() {
+    // Create a NUM_ROWS x NUM_ROWS board with no queens on it.
+    let mut board = make_board(NUM_ROWS);
+
+    let metrics = Metrics::new();
+    let start = Instant::now();
+    let success = place_queens_1(&mut board, 0, 0, &metrics);
+    let duration = start.elapsed();
+
+    println!("Time: {:?}", duration);
+    println!("Metrics: {}", metrics.report());
+
+    if success {
+        println!("Success!");
+    } else {
+        println!("Could not find a tour.");
+    }
+
+    dump_board(&board);
+}
@@ -0,0 +1,32 @@
+use algo::args::Args;
+use algo::error::{exit_on_error, Result};
+use algo::io::print_vec;
+use algo::metrics::Metrics;
+use algo::prompt::Prompt;
+use algo::sorting::{check_sorted, make_random_vec, quicksort};
+
+
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args = Args::from_env(&[]);
+
+    // Prepare a random source, seeded from --seed or the clock.
+    let mut prng = args.source(None)?;
+
+    let num_items = args.ask(&Prompt::new("Enter number of items:").min(0))?;
+    let max_value = args.ask(&Prompt::new("Enter non-inclusive maximum item value:").min(1))?;
+    let mut vec = make_random_vec(&mut prng, num_items, max_value);
+    print_vec(&vec, 20);
+    let metrics = Metrics::new();
+    quicksort(&mut vec, &metrics);
+    print_vec(&vec, 20);
+    check_sorted(&vec);
+    println!("Metrics: {}", metrics.report());
+    return Ok(());
+}
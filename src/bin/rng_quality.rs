@@ -0,0 +1,33 @@
+use algo::args::Args;
+use algo::error::{exit_on_error, Error, Result};
+use algo::rng::quality::{print_report, run_battery, NUM_SAMPLES};
+use algo::rng::{make_source, SOURCE_NAMES};
+
+const SEED: u32 = 1337;
+
+
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    // Test every generator, or just the one named by --rng.
+    let args = Args::from_env(&[]);
+    let seed = args.parsed_or("seed", SEED)?;
+    let names: Vec<String> = match args.value("rng") {
+        Some(name) => vec![name.to_string()],
+        None => SOURCE_NAMES.iter().map(|name| name.to_string()).collect(),
+    };
+    println!("Seed: {seed}, Samples per test: {NUM_SAMPLES}\n");
+
+    for name in names {
+        let mut rng = make_source(&name, seed as u64)
+            .ok_or_else(|| Error::InvalidArgument(format!("unknown generator {name}")))?;
+        let results = run_battery(&mut rng, NUM_SAMPLES);
+        print_report(&name, &results);
+    }
+    return Ok(());
+}
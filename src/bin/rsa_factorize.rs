@@ -0,0 +1,57 @@
+use std::time::Instant;
+
+use algo::args::Args;
+use algo::error::{exit_on_error, Result};
+use algo::io::print_numbers;
+use algo::metrics::Metrics;
+use algo::number_theory::{
+    find_factors, find_factors_sieve, multiply_vector, sieve_of_eratosthenes_with_progress, sieve_to_primes,
+};
+use algo::progress::{Progress, ProgressBar, DEFAULT_INTERVAL};
+use algo::prompt::Prompt;
+
+
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args = Args::from_env(&["progress"]);
+
+    // The sieve takes a while, so --progress shows how far it has got.
+    let mut metrics = Metrics::new();
+    if args.flag("progress") {
+        metrics = metrics.with_progress(Progress::new(ProgressBar::stderr()).with_interval(DEFAULT_INTERVAL));
+    }
+    let primes: Vec<i64> = sieve_to_primes(&sieve_of_eratosthenes_with_progress(1000000000, &metrics));
+    metrics.finish_progress();
+
+    loop {
+        let num: i64 = args.ask(&Prompt::new("Num: "))?;
+        if num <= 0 {
+            break;
+        }
+
+        // Find the factors the slow way.
+        let start1 = Instant::now();
+        let factors1 = find_factors(num)?;
+        let duration1 = start1.elapsed();
+        println!("find_factors: {:?} seconds", duration1);
+        print_numbers(&factors1);
+        println!("Product: {}", multiply_vector(&factors1));
+        println!();
+
+        // Use the Eratosthenes' sieve to find the factors.
+        let start2 = Instant::now();
+        let factors2 = find_factors_sieve(&primes, num)?;
+        let duration2 = start2.elapsed();
+        println!("find_factors_sieve: {:?} seconds", duration2);
+        print_numbers(&factors2);
+        println!("Product: {}", multiply_vector(&factors2));
+        println!();
+    }
+    return Ok(());
+}
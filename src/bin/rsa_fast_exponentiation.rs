@@ -0,0 +1,44 @@
+use algo::args::Args;
+use algo::error::{exit_on_error, Result};
+use algo::number_theory::{fast_exp, fast_exp_mod};
+use algo::prompt::Prompt;
+
+
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args = Args::from_env(&[]);
+    loop {
+        let num: i64 = args.ask(&Prompt::new("Num: "))?;
+        let pow = args.ask(&Prompt::new("Pow: ").min(0))?;
+        let modulus = args.ask(&Prompt::new("Mod: ").min(1))?;
+
+        // Compare against the standard library, which reports overflow as None.
+        let expected = u32::try_from(pow).ok().and_then(|pow| num.checked_pow(pow));
+        let show = |value: Option<i64>| match value {
+            Some(value) => value.to_string(),
+            None => String::from("overflow"),
+        };
+
+        // fast_exp
+        match fast_exp(num, pow) {
+            Ok(value) => println!("    fast_exp     = {value}"),
+            Err(error) => println!("    fast_exp     : {error}"),
+        }
+        println!("    Num ^ Pow    = {}", show(expected));
+        println!();
+
+        // fast_exp_mod
+        match fast_exp_mod(num, pow, modulus) {
+            Ok(value) => println!("    fast_exp_mod = {value}"),
+            Err(error) => println!("    fast_exp_mod : {error}"),
+        }
+        println!("    Num ^ Pow    = {}", show(expected.and_then(|value| value.checked_rem_euclid(modulus))));
+        println!("----------");
+    }
+}
@@ -0,0 +1,46 @@
+use algo::args::Args;
+use algo::error::{exit_on_error, Result};
+use algo::number_theory::find_prime;
+use algo::prompt::Prompt;
+
+
+
+const NUM_TESTS: i64 = 20;
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args = Args::from_env(&[]);
+
+    // Prepare a random source, seeded from --seed or the clock.
+    let mut prng = args.source(None)?;
+
+    // Display the probability that a number is prime
+    // if it passes all NUM_TESTS tests.
+    let probability = (1.0 - 0.5_f64.powf(NUM_TESTS as f64)) * 100.0;
+    println!("Probability: {}%\n", probability);
+
+    // Generate random primes.
+    loop {
+        // Get the number of digits.
+        let num_digits = args.ask(&Prompt::new("# Digits (max 9): ").max(9))?;
+        if num_digits < 1 {
+            break;
+        }
+
+        // Calculate minimum and maximum values.
+        let mut min = 10i64.pow((num_digits - 1) as u32);
+        let max = 10 * min;
+        if min == 1 {
+            min = 2; // 1 is not prime.
+        }
+
+        // Find a prime.
+        println!("Prime: {}", find_prime(&mut prng, min as i32, max as i32, NUM_TESTS)?);
+    }
+    return Ok(());
+}
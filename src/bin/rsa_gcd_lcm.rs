@@ -0,0 +1,35 @@
+use std::env;
+
+use algo::error::{exit_on_error, parse, Result};
+use algo::metrics::{Counter, Metrics};
+use algo::number_theory::{gcd_traced, lcm_with_gcd};
+use algo::trace::Tracer;
+
+
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args: Vec<String> = env::args().collect();
+    let trace = args.len() == 4 && args[3] == "--trace";
+    if args.len() != 3 && !trace {
+        println!("Usage: rsa_gcd_lcm <a> <b> [--trace]");
+        return Ok(());
+    }
+    let (a, b): (i64, i64) = (parse(&args[1], "integer")?, parse(&args[2], "integer")?);
+    let metrics = match trace {
+        true => Metrics::with_tracer(Tracer::default()),
+        false => Metrics::new(),
+    };
+    let gcdv = gcd_traced(a, b, &metrics)?;
+    if let Some(tracer) = metrics.take_tracer() {
+        print!("{}", tracer.to_text(None));
+    }
+    println!("GCD({}, {}) = {}, {} calls", a, b, gcdv, metrics.get(Counter::Calls));
+    println!("LCM({}, {}) = {}", a, b, lcm_with_gcd(a, b, gcdv)?);
+    return Ok(());
+}
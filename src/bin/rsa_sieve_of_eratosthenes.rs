@@ -0,0 +1,31 @@
+use algo::args::Args;
+use algo::error::{exit_on_error, Result};
+use algo::io::print_numbers;
+use algo::number_theory::{print_sieve, sieve_of_eratosthenes, sieve_to_primes};
+use algo::prompt::Prompt;
+
+
+
+// The sieve takes one byte per number.
+const MAX: i64 = 1_000_000_000;
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args = Args::from_env(&[]);
+    let max = args.ask(&Prompt::new("Max: ").range(0, MAX))?;
+    let sieve = sieve_of_eratosthenes(max as usize);
+    if max < 1000 {
+        print_sieve(&sieve);
+    }
+
+    let primes = sieve_to_primes(&sieve);
+    if max < 1000 {
+        print_numbers(&primes);
+    }
+    return Ok(());
+}
@@ -0,0 +1,47 @@
+use algo::args::Args;
+use algo::error::{exit_on_error, Result};
+use algo::number_theory::fast_exp_mod;
+use algo::prompt::Prompt;
+use algo::rsa::generate_keys;
+
+
+
+fn main() {
+    exit_on_error(run());
+}
+
+
+
+fn run() -> Result<()> {
+    let args = Args::from_env(&[]);
+
+    // Prepare a random source, seeded from --seed or the clock.
+    let mut prng = args.source(None)?;
+
+    let keys = generate_keys(&mut prng)?;
+    let (n, e, d) = (keys.n, keys.e, keys.d);
+
+    println!("*** Public ***");
+    println!("Public key modulus (n):{n}");
+    println!("Public key exponent (e):{e}");
+    println!();
+    println!("*** Private ***");
+    println!("Primes (p, q):{}, {}", keys.p, keys.q);
+    println!("λ(n):{}", keys.lambda_n);
+    println!("d:{d}");
+    println!();
+
+    let (mut m, mut ciphertext, mut plaintext): (i64, i64, i64);
+    loop {
+        m = args.ask(&Prompt::new(&format!("Enter a number in range [2, {} - 2] which will be the message, but experiment with numbers out of that range too:", n)))?;
+        if m < 0 {
+            break;
+        }
+
+        ciphertext = fast_exp_mod(m, e, n)?;
+        plaintext = fast_exp_mod(ciphertext, d, n)?;
+        println!("Ciphertext:{ciphertext}");
+        println!("Plaintext:{plaintext}");
+    }
+    return Ok(());
+}
@@ -0,0 +1,19 @@
+use algo::backtracking::hanoi::{draw_posts, make_posts, move_disks};
+use algo::metrics::{Counter, Metrics};
+
+const NUM_DISKS: usize = 3;
+
+
+
+fn main() {
+    // Make three posts with the disks on the first post.
+    let mut posts = make_posts(NUM_DISKS);
+
+    // Draw the initial setup.
+    draw_posts(&posts);
+
+    // Move the disks.
+    let metrics = Metrics::new();
+    move_disks(&mut posts, NUM_DISKS, 0, 1, 2, true, &metrics);
+    println!("Ok, took {} moves", metrics.get(Counter::Moves));
+}
@@ -0,0 +1,126 @@
+//! Limits on how long a search may run: a deadline, a time limit, a limit
+//! on the number of calls, or a token another thread can cancel.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+
+
+// A flag shared between the search and whoever may want to stop it.
+// Clones share the flag.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        return CancelToken::default();
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        return self.0.load(Ordering::Relaxed);
+    }
+}
+
+
+
+// Why a search stopped before it finished.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StopReason {
+    Deadline,
+    NodeLimit,
+    Cancelled,
+}
+
+impl StopReason {
+    pub const ALL: [StopReason; 3] = [StopReason::Deadline, StopReason::NodeLimit, StopReason::Cancelled];
+
+    pub fn name(&self) -> &'static str {
+        return match self {
+            StopReason::Deadline => "deadline",
+            StopReason::NodeLimit => "node limit",
+            StopReason::Cancelled => "cancelled",
+        };
+    }
+}
+
+
+
+// Any combination of limits; a search stops at the first one it reaches.
+#[derive(Clone, Debug, Default)]
+pub struct Budget {
+    pub deadline: Option<Instant>,
+    // How long the search may run, counted from when it starts.
+    pub time_limit: Option<Duration>,
+    // The most calls the search may make.
+    pub max_nodes: Option<u64>,
+    pub cancel: Option<CancelToken>,
+}
+
+impl Budget {
+    // A budget with no limits.
+    pub fn unlimited() -> Self {
+        return Budget::default();
+    }
+
+    // Stop once the search has run for the time limit.
+    pub fn with_time_limit(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        return self;
+    }
+
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        return self;
+    }
+
+    pub fn with_max_nodes(mut self, max_nodes: u64) -> Self {
+        self.max_nodes = Some(max_nodes);
+        return self;
+    }
+
+    pub fn with_cancel(mut self, cancel: CancelToken) -> Self {
+        self.cancel = Some(cancel);
+        return self;
+    }
+
+    // Return the limit reached after num_nodes calls, if any. started is
+    // when the search started, which only a time limit needs.
+    pub fn check(&self, num_nodes: u64, started: Option<Instant>) -> Option<StopReason> {
+        if self.cancel.as_ref().is_some_and(|cancel| cancel.is_cancelled()) {
+            return Some(StopReason::Cancelled);
+        }
+        if self.max_nodes.is_some_and(|max_nodes| num_nodes > max_nodes) {
+            return Some(StopReason::NodeLimit);
+        }
+        if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return Some(StopReason::Deadline);
+        }
+        if let (Some(limit), Some(started)) = (self.time_limit, started) {
+            if started.elapsed() >= limit {
+                return Some(StopReason::Deadline);
+            }
+        }
+        return None;
+    }
+}
+
+
+
+// A search's result and, if it ran out of budget, why. A search that
+// stopped early returns the best solution it had found by then.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome<T> {
+    pub result: T,
+    pub stopped: Option<StopReason>,
+}
+
+impl<T> Outcome<T> {
+    pub fn is_complete(&self) -> bool {
+        return self.stopped.is_none();
+    }
+}
@@ -0,0 +1,172 @@
+//! Seeded property checks: run a property on many generated inputs and
+//! shrink the first failing input to a small counterexample.
+
+use std::fmt;
+use std::fmt::Debug;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+
+use crate::rng::SplitMix64;
+
+// Give up shrinking after this many steps, in case a shrinker never runs dry.
+const MAX_SHRINKS: usize = 10_000;
+
+
+
+// A failing input, before and after shrinking.
+pub struct Failure<T> {
+    // The case that failed and the seed its generator was given, so
+    // generate(&mut SplitMix64::from_seed(seed)) makes the input again.
+    pub case: usize,
+    pub seed: u64,
+    pub original: T,
+    pub shrunk: T,
+    pub num_shrinks: usize,
+    // Why the property fails on the shrunk input.
+    pub message: String,
+}
+
+impl<T: Debug> fmt::Display for Failure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "property failed on case {} (seed {}): {}", self.case, self.seed, self.message)?;
+        writeln!(f, "shrunk in {} steps to {:?}", self.num_shrinks, self.shrunk)?;
+        return write!(f, "original input {:?}", self.original);
+    }
+}
+
+
+
+// Run the property on num_cases generated inputs. Case i gets a generator
+// seeded with seed + i. Return the first failure, shrunk, or None if the
+// property held every time. A panic counts as a failure.
+pub fn check<T: Clone>(
+    seed: u64,
+    num_cases: usize,
+    mut generate: impl FnMut(&mut SplitMix64) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Option<Failure<T>> {
+    for case in 0..num_cases {
+        let case_seed = seed.wrapping_add(case as u64);
+        let input = generate(&mut SplitMix64::from_seed(case_seed));
+        if let Err(message) = run(&property, &input) {
+            let (shrunk, num_shrinks, message) = shrink_failure(&input, message, &shrink, &property);
+            return Some(Failure {
+                case,
+                seed: case_seed,
+                original: input,
+                shrunk,
+                num_shrinks,
+                message,
+            });
+        }
+    }
+    return None;
+}
+
+
+
+// Like check, but panic with the counterexample if the property fails.
+pub fn assert_property<T: Clone + Debug>(
+    seed: u64,
+    num_cases: usize,
+    generate: impl FnMut(&mut SplitMix64) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    if let Some(failure) = check(seed, num_cases, generate, shrink, property) {
+        panic!("{failure}");
+    }
+}
+
+
+
+// Shrink greedily: move to the first candidate that still fails until no
+// candidate does. Return the smallest failing input, the number of steps
+// taken and the property's message for it.
+fn shrink_failure<T: Clone>(
+    input: &T,
+    message: String,
+    shrink: &impl Fn(&T) -> Vec<T>,
+    property: &impl Fn(&T) -> Result<(), String>,
+) -> (T, usize, String) {
+    let (mut smallest, mut message) = (input.clone(), message);
+    let mut num_shrinks = 0;
+    'shrinking: while num_shrinks < MAX_SHRINKS {
+        for candidate in shrink(&smallest) {
+            if let Err(candidate_message) = run(property, &candidate) {
+                smallest = candidate;
+                message = candidate_message;
+                num_shrinks += 1;
+                continue 'shrinking;
+            }
+        }
+        break;
+    }
+    return (smallest, num_shrinks, message);
+}
+
+
+
+fn run<T>(property: &impl Fn(&T) -> Result<(), String>, input: &T) -> Result<(), String> {
+    return match panic::catch_unwind(AssertUnwindSafe(|| property(input))) {
+        Ok(result) => result,
+        Err(payload) => {
+            let reason = payload
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| payload.downcast_ref::<&str>().map(|reason| reason.to_string()))
+                .unwrap_or_default();
+            Err(format!("panicked: {reason}"))
+        }
+    };
+}
+
+
+
+// Smaller versions of a vector: empty, each half, each item removed, and
+// each item shrunk in place. Shorter candidates come first.
+pub fn shrink_vec<T: Clone>(items: &Vec<T>, shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates: Vec<Vec<T>> = vec![];
+    if items.is_empty() {
+        return candidates;
+    }
+    candidates.push(vec![]);
+    if items.len() > 2 {
+        let half = items.len() / 2;
+        candidates.push(items[..half].to_vec());
+        candidates.push(items[half..].to_vec());
+    }
+    if items.len() > 1 {
+        for i in 0..items.len() {
+            let mut smaller = items.clone();
+            smaller.remove(i);
+            candidates.push(smaller);
+        }
+    }
+    for i in 0..items.len() {
+        for item in shrink_item(&items[i]) {
+            let mut smaller = items.clone();
+            smaller[i] = item;
+            candidates.push(smaller);
+        }
+    }
+    return candidates;
+}
+
+
+
+// Values between value and target, nearest the target first: the target,
+// halfway there, and one step closer.
+pub fn shrink_i64(value: i64, target: i64) -> Vec<i64> {
+    let mut candidates: Vec<i64> = vec![];
+    if value == target {
+        return candidates;
+    }
+    for candidate in [target, target + (value - target) / 2, value - (value - target).signum()] {
+        if candidate != value && !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+    }
+    return candidates;
+}
@@ -0,0 +1,74 @@
+//! The error type returned by the algorithms and input helpers that can fail.
+
+use std::fmt;
+use std::io;
+use std::process;
+use std::str::FromStr;
+
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    // Text that could not be read as the expected kind of value.
+    ParseError { input: String, expected: String },
+    // The input ran out before a value was read.
+    EndOfInput,
+    Io(String),
+    // a has no inverse modulo n because they share a factor.
+    NotInvertible { a: i64, n: i64 },
+    // The result does not fit in the return type; holds the calculation.
+    Overflow(String),
+    EmptyInput,
+    // A counting sort key outside [0, max).
+    KeyOutOfRange { key: i64, max: i64 },
+    InvalidArgument(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::ParseError { input, expected } => write!(f, "{input:?} is not a valid {expected}"),
+            Error::EndOfInput => write!(f, "end of input"),
+            Error::Io(message) => write!(f, "I/O error: {message}"),
+            Error::NotInvertible { a, n } => write!(f, "{a} is not invertible modulo {n}"),
+            Error::Overflow(calculation) => write!(f, "{calculation} overflows"),
+            Error::EmptyInput => write!(f, "the input is empty"),
+            Error::KeyOutOfRange { key, max } => write!(f, "key {key} is outside [0, {max})"),
+            Error::InvalidArgument(message) => write!(f, "{message}"),
+        };
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        return Error::Io(error.to_string());
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+
+
+// Parse text, ignoring surrounding whitespace, as a value described by expected.
+pub fn parse<T: FromStr>(text: &str, expected: &str) -> Result<T> {
+    return text.trim().parse::<T>().map_err(|_| Error::ParseError {
+        input: text.trim().to_string(),
+        expected: expected.to_string(),
+    });
+}
+
+
+
+// Finish a program: print the error, if any, and exit with status 1.
+// Running out of input ends the program quietly.
+pub fn exit_on_error(result: Result<()>) {
+    match result {
+        Ok(()) | Err(Error::EndOfInput) => {}
+        Err(error) => {
+            eprintln!("Error: {error}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! C interface to the sorts, the number theory routines and the knapsack
+//! solvers.
+//!
+//! Every function returns ALGO_OK or a negative error code and writes its
+//! results through pointers; panics are caught and reported as
+//! ALGO_ERR_PANIC instead of unwinding into C.
+//!
+//! Ownership: buffers passed in belong to the caller and are only used
+//! during the call. A pointer may be NULL when its length is 0. The only
+//! buffer the library hands out, from algo_find_factors, must be released
+//! with algo_free_i64.
+
+// build.rs generates the header from this file, and include/algo.h must
+// be a copy of it. The safety rules are the ownership rules above, which
+// the header repeats.
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::c_char;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::ptr;
+use std::slice;
+
+use crate::error::Error;
+use crate::knapsack::{
+    branch_and_bound, dynamic_programming, exhaustive_search, rods_technique, rods_technique_sorted, Item, Solver,
+};
+use crate::metrics::Metrics;
+use crate::number_theory::{fast_exp_mod, find_factors, gcd, is_probably_prime, lcm};
+use crate::rng::SplitMix64;
+use crate::rsa::inverse_mod;
+use crate::sorting::{counting_sort_numbers, quicksort};
+
+pub const ALGO_OK: i32 = 0;
+// A required pointer was NULL.
+pub const ALGO_ERR_NULL_POINTER: i32 = -1;
+pub const ALGO_ERR_INVALID_ARGUMENT: i32 = -2;
+// The result does not fit in its type.
+pub const ALGO_ERR_OVERFLOW: i32 = -3;
+pub const ALGO_ERR_NOT_INVERTIBLE: i32 = -4;
+// A counting sort key is outside [0, max).
+pub const ALGO_ERR_KEY_OUT_OF_RANGE: i32 = -5;
+// A bug in the library; please report it.
+pub const ALGO_ERR_PANIC: i32 = -6;
+
+// The solvers algo_knapsack can use.
+pub const ALGO_KNAPSACK_EXHAUSTIVE: i32 = 0;
+pub const ALGO_KNAPSACK_BRANCH_AND_BOUND: i32 = 1;
+pub const ALGO_KNAPSACK_RODS: i32 = 2;
+pub const ALGO_KNAPSACK_RODS_SORTED: i32 = 3;
+pub const ALGO_KNAPSACK_DYNAMIC_PROGRAMMING: i32 = 4;
+
+
+
+// An error code on its way out of a function.
+struct Code(i32);
+
+impl From<Error> for Code {
+    fn from(error: Error) -> Self {
+        return Code(match error {
+            Error::Overflow(_) => ALGO_ERR_OVERFLOW,
+            Error::NotInvertible { .. } => ALGO_ERR_NOT_INVERTIBLE,
+            Error::KeyOutOfRange { .. } => ALGO_ERR_KEY_OUT_OF_RANGE,
+            _ => ALGO_ERR_INVALID_ARGUMENT,
+        });
+    }
+}
+
+type Status = Result<(), Code>;
+
+
+
+// Run the body, turning its error or a panic into an error code.
+fn guard(body: impl FnOnce() -> Status) -> i32 {
+    return match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => ALGO_OK,
+        Ok(Err(Code(code))) => code,
+        Err(_) => ALGO_ERR_PANIC,
+    };
+}
+
+unsafe fn slice_ref<'a, T>(data: *const T, len: usize) -> Result<&'a [T], Code> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if data.is_null() {
+        return Err(Code(ALGO_ERR_NULL_POINTER));
+    }
+    return Ok(slice::from_raw_parts(data, len));
+}
+
+unsafe fn slice_mut<'a, T>(data: *mut T, len: usize) -> Result<&'a mut [T], Code> {
+    if len == 0 {
+        return Ok(&mut []);
+    }
+    if data.is_null() {
+        return Err(Code(ALGO_ERR_NULL_POINTER));
+    }
+    return Ok(slice::from_raw_parts_mut(data, len));
+}
+
+unsafe fn write<T>(out: *mut T, value: T) -> Status {
+    if out.is_null() {
+        return Err(Code(ALGO_ERR_NULL_POINTER));
+    }
+    *out = value;
+    return Ok(());
+}
+
+fn invalid(message: &str) -> Code {
+    return Code::from(Error::InvalidArgument(message.to_string()));
+}
+
+
+
+// Return a description of an error code. The string is static; do not free it.
+#[no_mangle]
+pub extern "C" fn algo_error_message(code: i32) -> *const c_char {
+    let message: &'static [u8] = match code {
+        ALGO_OK => b"ok\0",
+        ALGO_ERR_NULL_POINTER => b"a required pointer was NULL\0",
+        ALGO_ERR_INVALID_ARGUMENT => b"invalid argument\0",
+        ALGO_ERR_OVERFLOW => b"the result overflows\0",
+        ALGO_ERR_NOT_INVERTIBLE => b"the number is not invertible\0",
+        ALGO_ERR_KEY_OUT_OF_RANGE => b"a key is out of range\0",
+        ALGO_ERR_PANIC => b"internal error\0",
+        _ => b"unknown error code\0",
+    };
+    return message.as_ptr() as *const c_char;
+}
+
+
+
+// Sort len values in place.
+#[no_mangle]
+pub unsafe extern "C" fn algo_quicksort_i32(data: *mut i32, len: usize) -> i32 {
+    return guard(|| {
+        quicksort(slice_mut(data, len)?, &Metrics::new());
+        return Ok(());
+    });
+}
+
+// Sort len values in place.
+#[no_mangle]
+pub unsafe extern "C" fn algo_quicksort_i64(data: *mut i64, len: usize) -> i32 {
+    return guard(|| {
+        quicksort(slice_mut(data, len)?, &Metrics::new());
+        return Ok(());
+    });
+}
+
+// Sort len values in [0, max) in place. On ALGO_ERR_KEY_OUT_OF_RANGE the
+// data is left unchanged.
+#[no_mangle]
+pub unsafe extern "C" fn algo_counting_sort_i32(data: *mut i32, len: usize, max: i32) -> i32 {
+    return guard(|| {
+        counting_sort_numbers(slice_mut(data, len)?, max as i64)?;
+        return Ok(());
+    });
+}
+
+// Sort len values in [0, max) in place. On ALGO_ERR_KEY_OUT_OF_RANGE the
+// data is left unchanged.
+#[no_mangle]
+pub unsafe extern "C" fn algo_counting_sort_i64(data: *mut i64, len: usize, max: i64) -> i32 {
+    return guard(|| {
+        counting_sort_numbers(slice_mut(data, len)?, max)?;
+        return Ok(());
+    });
+}
+
+
+
+// The greatest common divisor, which is never negative. Fails with
+// ALGO_ERR_OVERFLOW if it is 2^63, as when a is INT64_MIN and b is 0.
+#[no_mangle]
+pub unsafe extern "C" fn algo_gcd(a: i64, b: i64, result: *mut i64) -> i32 {
+    return guard(|| write(result, gcd(a, b)?));
+}
+
+// The least common multiple, which is never negative. It is 0 if a or b is.
+#[no_mangle]
+pub unsafe extern "C" fn algo_lcm(a: i64, b: i64, result: *mut i64) -> i32 {
+    return guard(|| write(result, lcm(a, b)?));
+}
+
+// num^pow mod modulus, in [0, modulus). modulus must be positive and pow
+// must not be negative.
+#[no_mangle]
+pub unsafe extern "C" fn algo_fast_exp_mod(num: i64, pow: i64, modulus: i64, result: *mut i64) -> i32 {
+    return guard(|| write(result, fast_exp_mod(num, pow, modulus)?));
+}
+
+// The x in [0, n) with a * x = 1 mod n. Fails with ALGO_ERR_NOT_INVERTIBLE
+// if a and n share a factor.
+#[no_mangle]
+pub unsafe extern "C" fn algo_inverse_mod(a: i64, n: i64, result: *mut i64) -> i32 {
+    return guard(|| write(result, inverse_mod(a, n)?));
+}
+
+// Whether p passes num_tests Fermat tests with bases drawn from a generator
+// seeded with seed. A prime always passes; a composite passes each test
+// with probability at most 1/2, except for Carmichael numbers.
+#[no_mangle]
+pub unsafe extern "C" fn algo_is_probable_prime(p: i32, num_tests: i32, seed: u64, result: *mut bool) -> i32 {
+    return guard(|| {
+        if num_tests < 1 {
+            return Err(invalid("num_tests must be at least 1"));
+        }
+        return write(result, is_probably_prime(p, num_tests as i64, &mut SplitMix64::from_seed(seed)));
+    });
+}
+
+// The prime factors of num > 0 in increasing order. *factors is set to a
+// new buffer of *len values, which the caller must release with
+// algo_free_i64(*factors, *len). Nothing is allocated on failure.
+#[no_mangle]
+pub unsafe extern "C" fn algo_find_factors(num: i64, factors: *mut *mut i64, len: *mut usize) -> i32 {
+    return guard(|| {
+        if factors.is_null() || len.is_null() {
+            return Err(Code(ALGO_ERR_NULL_POINTER));
+        }
+        let found = find_factors(num)?.into_boxed_slice();
+        *len = found.len();
+        *factors = Box::into_raw(found) as *mut i64;
+        return Ok(());
+    });
+}
+
+// Release a buffer returned by algo_find_factors. NULL is ignored.
+#[no_mangle]
+pub unsafe extern "C" fn algo_free_i64(data: *mut i64, len: usize) {
+    if !data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(data, len)));
+    }
+}
+
+
+
+// Solve the 0/1 knapsack problem for len items with the given values and
+// weights, all non-negative, with one of the ALGO_KNAPSACK_ solvers.
+// selected must hold (len + 7) / 8 bytes; item i is selected if bit i % 8
+// of selected[i / 8] is set. The exhaustive and rods solvers take time
+// exponential in len, the dynamic programming solver time and memory
+// proportional to len * allowed_weight.
+#[no_mangle]
+pub unsafe extern "C" fn algo_knapsack(
+    solver: i32,
+    values: *const i32,
+    weights: *const i32,
+    len: usize,
+    allowed_weight: i32,
+    selected: *mut u8,
+    total_value: *mut i32,
+) -> i32 {
+    return guard(|| {
+        let values = slice_ref(values, len)?;
+        let weights = slice_ref(weights, len)?;
+        let selected = slice_mut(selected, len.div_ceil(8))?;
+        if total_value.is_null() {
+            return Err(Code(ALGO_ERR_NULL_POINTER));
+        }
+        let solve: &Solver = match solver {
+            ALGO_KNAPSACK_EXHAUSTIVE => &exhaustive_search,
+            ALGO_KNAPSACK_BRANCH_AND_BOUND => &branch_and_bound,
+            ALGO_KNAPSACK_RODS => &rods_technique,
+            ALGO_KNAPSACK_RODS_SORTED => &rods_technique_sorted,
+            ALGO_KNAPSACK_DYNAMIC_PROGRAMMING => &dynamic_programming,
+            _ => return Err(invalid("unknown solver")),
+        };
+        if len > i32::MAX as usize {
+            return Err(invalid("too many items"));
+        }
+
+        let mut items: Vec<Item> = Vec::with_capacity(len);
+        for i in 0..len {
+            items.push(Item {
+                id: i as i32,
+                value: values[i],
+                weight: weights[i],
+                is_selected: false,
+                blocked_by: -1,
+                block_list: vec![],
+            });
+        }
+        // The solvers reject negative numbers and values that overflow.
+        let (solution, value) = solve(&mut items, allowed_weight, &Metrics::new())?;
+        selected.fill(0);
+        for item in solution.iter().filter(|item| item.is_selected) {
+            selected[item.id as usize / 8] |= 1 << (item.id % 8);
+        }
+        *total_value = value;
+        return Ok(());
+    });
+}
@@ -0,0 +1,37 @@
+//! Console helpers shared by the interactive programs.
+
+use std::fmt::Display;
+
+
+
+// Print at most num_items items.
+pub fn print_vec<T: Display>(vec: &Vec<T>, num_items: i32) {
+    let mut max = vec.len();
+    if max > num_items as usize {
+        max = num_items as usize;
+    }
+
+    let mut string = String::new();
+    string.push('[');
+
+    if max > 0usize {
+        string.push_str(&vec[0].to_string());
+    }
+
+    for i in 1usize..max {
+        string.push(' ');
+        string.push_str(&vec[i].to_string());
+    }
+    string.push(']');
+    println!("{string}");
+}
+
+
+
+// Print the vector of numbers.
+pub fn print_numbers(primes: &Vec<i64>) {
+    for prime in primes {
+        print!("{} ", prime);
+    }
+    println!();
+}
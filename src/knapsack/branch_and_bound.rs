@@ -0,0 +1,85 @@
+use crate::error::Result;
+use crate::metrics::{Counter, Metrics};
+
+use super::{check_instance, copy_items, fits, incumbent, Item, Number};
+
+
+
+// Like exhaustive_search, but skip branches that cannot beat the best
+// solution so far. If the metrics' budget runs out, return that solution.
+pub fn branch_and_bound<V: Number, W: Number>(
+    items: &mut Vec<Item<V, W>>,
+    allowed_weight: W,
+    metrics: &Metrics,
+) -> Result<(Vec<Item<V, W>>, V)> {
+    // Every partial sum of the values is at most the total, so once that
+    // fits the search cannot overflow.
+    let total_value = check_instance(items, allowed_weight)?;
+    return Ok(do_branch_and_bound(items, allowed_weight, V::ZERO, V::ZERO, W::ZERO, total_value, 0, metrics));
+}
+
+
+
+fn do_branch_and_bound<V: Number, W: Number>(
+    items: &mut Vec<Item<V, W>>,
+    allowed_weight: W,
+    mut best_value: V,
+    current_value: V,
+    current_weight: W,
+    remaining_value: V,
+    next_index: i32,
+    metrics: &Metrics,
+) -> (Vec<Item<V, W>>, V) {
+    metrics.count(Counter::Calls);
+    metrics.progress("knapsack", next_index as usize, None);
+    return metrics.trace(
+        || format!("do_branch_and_bound({next_index}, {current_value}, {current_weight})"),
+        || {
+            if (next_index as usize) >= items.len() {
+                return (copy_items(items), current_value);
+            } else if metrics.out_of_budget() {
+                return (incumbent(items, next_index), current_value);
+            } else {
+                if current_value + remaining_value <= best_value {
+                    metrics.count(Counter::Prunes);
+                    metrics.note(|| format!("pruned: {current_value} + {remaining_value} <= {best_value}"));
+                    return (vec![], current_value);
+                }
+                let (mut included_solution, mut included_value) = (vec![], V::ZERO);
+                if fits(current_weight, items[next_index as usize].weight, allowed_weight) {
+                    items[next_index as usize].is_selected = true;
+                    (included_solution, included_value) = do_branch_and_bound(
+                        items,
+                        allowed_weight,
+                        best_value,
+                        current_value + items[next_index as usize].value,
+                        current_weight + items[next_index as usize].weight,
+                        remaining_value - items[next_index as usize].value,
+                        next_index + 1,
+                        metrics,
+                    );
+                    if included_value > best_value {
+                        best_value = included_value;
+                    }
+                }
+                items[next_index as usize].is_selected = false;
+                let (excluded_solution, excluded_value) = do_branch_and_bound(
+                    items,
+                    allowed_weight,
+                    best_value,
+                    current_value,
+                    current_weight,
+                    remaining_value - items[next_index as usize].value,
+                    next_index + 1,
+                    metrics,
+                );
+                if included_value >= excluded_value {
+                    return (included_solution, included_value);
+                } else {
+                    return (excluded_solution, excluded_value);
+                }
+            }
+        },
+        |(_, value)| value.to_string(),
+    );
+}
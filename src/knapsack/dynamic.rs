@@ -0,0 +1,94 @@
+use crate::error::{Error, Result};
+use crate::metrics::{Counter, Metrics};
+
+use super::{check_instance, copy_items, Item, Number};
+
+
+
+// Use dynamic programming to find a solution.
+// Return the best assignment and the value of that assignment.
+// The table has a column for every unit of weight up to the allowed
+// weight, so it fails if that many columns cannot be allocated.
+pub fn dynamic_programming<V: Number, W: Number>(
+    items: &mut Vec<Item<V, W>>,
+    allowed_weight: W,
+    metrics: &Metrics,
+) -> Result<(Vec<Item<V, W>>, V)> {
+    metrics.count(Counter::Calls);
+    check_instance(items, allowed_weight)?;
+    let num_items = items.len();
+    if num_items == 0 {
+        return Ok((copy_items(items), V::ZERO));
+    }
+
+    let too_big = || Error::Overflow(format!("a table of {num_items} items by {allowed_weight} weight"));
+    let num_cols = allowed_weight
+        .to_index()
+        .and_then(|max_weight| max_weight.checked_add(1))
+        .ok_or_else(too_big)?;
+    num_cols.checked_mul(num_items).ok_or_else(too_big)?;
+
+    let mut solution_value: Vec<Vec<V>> = Vec::with_capacity(num_items);
+    // Whether the best solution for items 0..=i and weight w takes item i.
+    // A flag rather than the previous weight, which can't tell an item that
+    // weighs nothing from one that was left out.
+    let mut take_item: Vec<Vec<bool>> = Vec::with_capacity(num_items);
+    for _ in 0..num_items {
+        let (mut values, mut takes) = (Vec::new(), Vec::new());
+        values.try_reserve_exact(num_cols).map_err(|_| too_big())?;
+        takes.try_reserve_exact(num_cols).map_err(|_| too_big())?;
+        values.resize(num_cols, V::ZERO);
+        takes.resize(num_cols, false);
+        solution_value.push(values);
+        take_item.push(takes);
+    }
+
+    // Weights too big to index can never fit.
+    let weights: Vec<usize> = items.iter().map(|item| item.weight.to_index().unwrap_or(usize::MAX)).collect();
+
+    for w in 0..num_cols {
+        if weights[0] <= w {
+            solution_value[0][w] = items[0].value;
+            take_item[0][w] = true;
+        } else {
+            solution_value[0][w] = V::ZERO;
+            take_item[0][w] = false;
+        }
+    }
+
+    // Fill in the remaining table rows.
+    for i in 1..num_items {
+        metrics.progress("knapsack", 0, Some(i as f64 / num_items as f64));
+        for w in 0..num_cols {
+            let value_without_i = solution_value[i - 1][w];
+            let mut value_with_i = V::ZERO;
+            if weights[i] <= w {
+                value_with_i = solution_value[i - 1][w - weights[i]]
+                    .checked_add(items[i].value)
+                    .ok_or_else(|| Error::Overflow(String::from("the sum of the values")))?;
+            }
+            if value_without_i >= value_with_i {
+                solution_value[i][w] = value_without_i;
+                take_item[i][w] = false;
+            } else {
+                solution_value[i][w] = value_with_i;
+                take_item[i][w] = true;
+            }
+        }
+    }
+
+    let mut back_i = (num_items as i32) - 1;
+    let mut back_w = num_cols - 1;
+
+    while back_i >= 0 {
+        if take_item[back_i as usize][back_w] {
+            items[back_i as usize].is_selected = true;
+            back_w -= weights[back_i as usize];
+        } else {
+            items[back_i as usize].is_selected = false;
+        }
+        back_i -= 1;
+    }
+
+    return Ok((copy_items(items), solution_value[num_items - 1][num_cols - 1]));
+}
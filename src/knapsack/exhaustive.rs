@@ -0,0 +1,71 @@
+use crate::error::Result;
+use crate::metrics::{Counter, Metrics};
+
+use super::{check_instance, copy_items, incumbent, solution_value, Item, Number};
+
+// A selection and its value, or None if it is too heavy.
+type Assignment<V, W> = (Vec<Item<V, W>>, Option<V>);
+
+
+
+// Recursively assign values in or out of the solution.
+// Return the best assignment and the value of that assignment,
+// counting the function calls we made in metrics. If the metrics' budget
+// runs out, return the best assignment found so far.
+pub fn exhaustive_search<V: Number, W: Number>(
+    items: &mut Vec<Item<V, W>>,
+    allowed_weight: W,
+    metrics: &Metrics,
+) -> Result<(Vec<Item<V, W>>, V)> {
+    check_instance(items, allowed_weight)?;
+    let (solution, value) = do_exhaustive_search(items, allowed_weight, 0, metrics)?;
+    // Selecting nothing always fits, so some assignment has a value.
+    return Ok((solution, value.unwrap_or(V::ZERO)));
+}
+
+
+
+// Return the best assignment and its value, or None if even the best is
+// too heavy.
+fn do_exhaustive_search<V: Number, W: Number>(
+    items: &mut Vec<Item<V, W>>,
+    allowed_weight: W,
+    next_index: i32,
+    metrics: &Metrics,
+) -> Result<Assignment<V, W>> {
+    metrics.count(Counter::Calls);
+    metrics.progress("knapsack", next_index as usize, None);
+    return metrics.trace(
+        || format!("do_exhaustive_search({next_index})"),
+        || -> Result<Assignment<V, W>> {
+            if (next_index as usize) >= items.len() {
+                return Ok((copy_items(items), solution_value(items, allowed_weight)?));
+            } else if metrics.out_of_budget() {
+                let mut solution = incumbent(items, next_index);
+                if solution_value(&solution, allowed_weight)?.is_none() {
+                    // The choices so far are already too heavy, so take nothing.
+                    solution = incumbent(&mut solution, 0);
+                }
+                let value = solution_value(&solution, allowed_weight)?;
+                return Ok((solution, value));
+            } else {
+                items[next_index as usize].is_selected = true;
+                let (included_solution, included_value) =
+                    do_exhaustive_search(items, allowed_weight, next_index + 1, metrics)?;
+                items[next_index as usize].is_selected = false;
+                let (excluded_solution, excluded_value) =
+                    do_exhaustive_search(items, allowed_weight, next_index + 1, metrics)?;
+                if included_value >= excluded_value {
+                    return Ok((included_solution, included_value));
+                } else {
+                    return Ok((excluded_solution, excluded_value));
+                }
+            }
+        },
+        |result| match result {
+            Ok((_, Some(value))) => value.to_string(),
+            Ok((_, None)) => String::from("too heavy"),
+            Err(error) => error.to_string(),
+        },
+    );
+}
@@ -0,0 +1,298 @@
+//! The 0/1 knapsack problem and the solvers we compare on it. Values and
+//! weights can be any Number; they are i32 unless said otherwise.
+
+mod branch_and_bound;
+mod dynamic;
+mod exhaustive;
+mod number;
+mod rods;
+
+pub use branch_and_bound::branch_and_bound;
+pub use dynamic::dynamic_programming;
+pub use exhaustive::exhaustive_search;
+pub use number::{Fixed, Number};
+pub use rods::{make_block_lists, rods_technique, rods_technique_sorted};
+
+use std::fs;
+use std::io;
+
+use crate::error::{Error, Result};
+use crate::metrics::Metrics;
+use crate::output::Record;
+use crate::rng::{RandomSource, Sampling};
+
+
+
+pub struct Item<V = i32, W = i32> {
+    pub id: i32,
+    pub value: V,
+    pub weight: W,
+    pub is_selected: bool,
+    pub blocked_by: i32,
+    pub block_list: Vec<i32>,
+}
+
+// A solver takes the items and the allowed weight and returns the best
+// assignment and the value of that assignment, counting its calls in the
+// metrics. It fails if the instance is invalid or its numbers overflow.
+pub type Solver<V = i32, W = i32> = dyn Fn(&mut Vec<Item<V, W>>, W, &Metrics) -> Result<(Vec<Item<V, W>>, V)>;
+
+
+
+// Make some random items.
+pub fn make_items(
+    prng: &mut impl RandomSource,
+    num_items: i32,
+    min_value: i32,
+    max_value: i32,
+    min_weight: i32,
+    max_weight: i32,
+) -> Vec<Item> {
+    let mut items: Vec<Item> = Vec::with_capacity(num_items as usize);
+    for i in 0..num_items {
+        let item = Item {
+            id: i,
+            value: prng.range_i32(min_value, max_value),
+            weight: prng.range_i32(min_weight, max_weight),
+            is_selected: false,
+            blocked_by: -1,
+            block_list: Vec::new(),
+        };
+        items.push(item);
+    }
+    return items;
+}
+
+
+
+// Return a copy of the items.
+pub fn copy_items<V: Number, W: Number>(items: &mut Vec<Item<V, W>>) -> Vec<Item<V, W>> {
+    let mut new_items: Vec<Item<V, W>> = Vec::with_capacity(items.len());
+    for item in items {
+        let new_item = Item {
+            id: item.id,
+            value: item.value,
+            weight: item.weight,
+            is_selected: item.is_selected,
+            blocked_by: item.blocked_by,
+            block_list: Vec::new(), //okay as it isn't used in printing solutions (the same goes for blocked_by and id)
+        };
+        new_items.push(new_item);
+    }
+    return new_items;
+}
+
+
+
+// Return the solution a search holds when its budget runs out: the choices
+// made for the items before next_index, with the rest left out.
+pub fn incumbent<V: Number, W: Number>(items: &mut Vec<Item<V, W>>, next_index: i32) -> Vec<Item<V, W>> {
+    for i in (next_index as usize)..items.len() {
+        items[i].is_selected = false;
+    }
+    return copy_items(items);
+}
+
+
+
+// Return the total value of the items.
+// If add_all is true, add up all items.
+// If add_all is false, only add up the selected items.
+pub fn sum_values<V: Number, W: Number>(items: &Vec<Item<V, W>>, add_all: bool) -> Result<V> {
+    return items
+        .iter()
+        .filter(|item| add_all || item.is_selected)
+        .try_fold(V::ZERO, |sum, item| sum.checked_add(item.value))
+        .ok_or_else(|| Error::Overflow(String::from("the sum of the values")));
+}
+
+
+
+// Return the total weight of the items.
+// If add_all is false, only add up the selected items.
+// If add_all is true, add up all items.
+pub fn sum_weights<V: Number, W: Number>(items: &Vec<Item<V, W>>, add_all: bool) -> Result<W> {
+    return items
+        .iter()
+        .filter(|item| add_all || item.is_selected)
+        .try_fold(W::ZERO, |sum, item| sum.checked_add(item.weight))
+        .ok_or_else(|| Error::Overflow(String::from("the sum of the weights")));
+}
+
+
+
+// Return the value of this solution, or None if it is too heavy. A total
+// weight too big for W is too heavy, but a total value too big for V fails.
+pub fn solution_value<V: Number, W: Number>(items: &Vec<Item<V, W>>, allowed_weight: W) -> Result<Option<V>> {
+    match sum_weights(items, false) {
+        Ok(weight) if weight <= allowed_weight => {}
+        _ => return Ok(None),
+    }
+    return Ok(Some(sum_values(items, false)?));
+}
+
+
+
+// Check an instance before solving it: nothing may be negative, and the
+// values must add up without overflowing. Return the total value.
+pub fn check_instance<V: Number, W: Number>(items: &Vec<Item<V, W>>, allowed_weight: W) -> Result<V> {
+    if allowed_weight < W::ZERO {
+        return Err(Error::InvalidArgument(format!("the allowed weight {allowed_weight} is negative")));
+    }
+    for item in items {
+        if item.value < V::ZERO || item.weight < W::ZERO {
+            return Err(Error::InvalidArgument(format!(
+                "item {} has a negative value or weight: ({}, {})",
+                item.id, item.value, item.weight
+            )));
+        }
+    }
+    return sum_values(items, true);
+}
+
+
+
+// Return true if an item of this weight can be added to a selection that
+// weighs current_weight.
+pub fn fits<W: Number>(current_weight: W, weight: W, allowed_weight: W) -> bool {
+    return current_weight.checked_add(weight).is_some_and(|total| total <= allowed_weight);
+}
+
+
+
+pub fn print_items<V: Number, W: Number>(items: &Vec<Item<V, W>>, all: bool) {
+    let mut num_printed = 0;
+    for i in 0..items.len() {
+        if all || items[i].is_selected {
+            print!("{}({}, {}) ", i, items[i].value, items[i].weight)
+        }
+        num_printed += 1;
+        if num_printed > 100 {
+            println!("...");
+            return;
+        }
+    }
+    println!();
+}
+
+
+
+// Read an instance file: the allowed weight on the first line, then one
+// "value weight" pair per line. Blank lines and lines starting with # are skipped.
+pub fn read_instance<V: Number, W: Number>(path: &str) -> io::Result<(Vec<Item<V, W>>, W)> {
+    let text = fs::read_to_string(path)?;
+    let bad_line = |line: &str| io::Error::new(io::ErrorKind::InvalidData, format!("bad line: {line}"));
+
+    let mut allowed_weight: Option<W> = None;
+    let mut items: Vec<Item<V, W>> = vec![];
+    for line in text.lines().map(|line| line.trim()) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match (allowed_weight, words.as_slice()) {
+            (None, [weight]) => allowed_weight = Some(weight.parse().map_err(|_| bad_line(line))?),
+            (Some(_), [value, weight]) => items.push(Item {
+                id: items.len() as i32,
+                value: value.parse().map_err(|_| bad_line(line))?,
+                weight: weight.parse().map_err(|_| bad_line(line))?,
+                is_selected: false,
+                blocked_by: -1,
+                block_list: Vec::new(),
+            }),
+            _ => return Err(bad_line(line)),
+        }
+    }
+    let allowed_weight = allowed_weight.ok_or_else(|| bad_line("missing allowed weight"))?;
+    return Ok((items, allowed_weight));
+}
+
+
+
+// Write the instance in the format read_instance expects.
+pub fn write_instance<V: Number, W: Number>(path: &str, items: &Vec<Item<V, W>>, allowed_weight: W) -> io::Result<()> {
+    let mut text = String::from("# allowed weight\n");
+    text.push_str(&format!("{allowed_weight}\n"));
+    text.push_str("# value weight\n");
+    for item in items {
+        text.push_str(&format!("{} {}\n", item.value, item.weight));
+    }
+    return fs::write(path, text);
+}
+
+
+
+// Display the instance's basic parameters. Totals that overflow are
+// shown as such.
+pub fn print_parameters<V: Number, W: Number>(items: &Vec<Item<V, W>>, allowed_weight: W) {
+    let show = |total: Result<String>| total.unwrap_or_else(|error| error.to_string());
+    println!("*** Parameters ***");
+    println!("# items:        {}", items.len());
+    println!("Total value:    {}", show(sum_values(items, true).map(|value| value.to_string())));
+    println!("Total weight:   {}", show(sum_weights(items, true).map(|weight| weight.to_string())));
+    println!("Allowed weight: {}", allowed_weight);
+    print_items(items, true);
+    println!();
+}
+
+
+
+// Run the algorithm on a copy of the items so the run isn't influenced by
+// a previous run. Return the solution and its value; the algorithm's
+// counters and its running time, as the "elapsed" timer, go to metrics.
+pub fn time_algorithm<V: Number, W: Number>(
+    alg: &Solver<V, W>,
+    items: &mut Vec<Item<V, W>>,
+    allowed_weight: W,
+    metrics: &Metrics,
+) -> Result<(Vec<Item<V, W>>, V)> {
+    let mut test_items = copy_items(items);
+    return metrics.time("elapsed", || alg(&mut test_items, allowed_weight, metrics));
+}
+
+
+
+// Describe a solution and the metrics of the run that found it as an
+// output record.
+pub fn solution_record<V: Number, W: Number>(
+    solver: &str,
+    solution: &Vec<Item<V, W>>,
+    total_value: V,
+    metrics: &Metrics,
+) -> Result<Record> {
+    let ids: Vec<i32> = solution
+        .iter()
+        .filter(|item| item.is_selected)
+        .map(|item| item.id)
+        .collect();
+    let mut record = Record::new("knapsack")
+        .with("solver", solver)
+        .with("value", total_value)
+        .with("weight", sum_weights(solution, false)?)
+        .with("selected", &ids);
+    metrics.add_to(&mut record);
+    return Ok(record);
+}
+
+
+
+// Run the algorithm. Display the elapsed time, solution and metrics.
+pub fn run_algorithm<V: Number, W: Number>(
+    alg: &Solver<V, W>,
+    items: &mut Vec<Item<V, W>>,
+    allowed_weight: W,
+) -> Result<()> {
+    let metrics = Metrics::new();
+    let (solution, total_value) = time_algorithm(alg, items, allowed_weight, &metrics)?;
+    println!("Elapsed: {:?}", metrics.elapsed("elapsed"));
+
+    print_items(&solution, false);
+    println!(
+        "Value: {}, Weight: {}",
+        total_value,
+        sum_weights(&solution, false)?
+    );
+    println!("Metrics: {}", metrics.report());
+    println!();
+    return Ok(());
+}
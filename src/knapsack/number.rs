@@ -0,0 +1,157 @@
+//! The kinds of number item values and weights can be: the primitive
+//! integers, and Fixed for decimals such as prices in cents.
+
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use crate::error::Error;
+use crate::output::Value;
+
+
+
+// What the solvers need from a value or weight. The solvers check the
+// totals that could overflow before they start, so inside a search plain
+// + and - are safe.
+pub trait Number:
+    Copy
+    + Ord
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + fmt::Display
+    + fmt::Debug
+    + FromStr
+    + Into<Value>
+    + Send
+    + Sync
+    + 'static
+{
+    const ZERO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    // The number of the type's smallest units in a weight, for indexing
+    // the dynamic programming table, or None if it is negative or does
+    // not fit in a usize.
+    fn to_index(self) -> Option<usize>;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    return <$t>::checked_add(self, other);
+                }
+
+                fn to_index(self) -> Option<usize> {
+                    return usize::try_from(self).ok();
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(i32, i64, u32, u64, u128);
+
+
+
+// A decimal with DECIMALS places, held as a whole number of its smallest
+// unit: Fixed<2> counts hundredths, so 12.34 is held as 1234.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const DECIMALS: u32>(pub i64);
+
+impl<const DECIMALS: u32> Fixed<DECIMALS> {
+    // The number of units in one.
+    pub const SCALE: i64 = 10i64.pow(DECIMALS);
+
+    pub fn units(self) -> i64 {
+        return self.0;
+    }
+
+    pub fn to_f64(self) -> f64 {
+        return self.0 as f64 / Self::SCALE as f64;
+    }
+}
+
+impl<const DECIMALS: u32> Add for Fixed<DECIMALS> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        return Fixed(self.0 + other.0);
+    }
+}
+
+impl<const DECIMALS: u32> Sub for Fixed<DECIMALS> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        return Fixed(self.0 - other.0);
+    }
+}
+
+impl<const DECIMALS: u32> Number for Fixed<DECIMALS> {
+    const ZERO: Self = Fixed(0);
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        return self.0.checked_add(other.0).map(Fixed);
+    }
+
+    fn to_index(self) -> Option<usize> {
+        return usize::try_from(self.0).ok();
+    }
+}
+
+impl<const DECIMALS: u32> fmt::Display for Fixed<DECIMALS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let units = self.0.unsigned_abs();
+        let scale = Self::SCALE as u64;
+        if DECIMALS == 0 {
+            return write!(f, "{sign}{units}");
+        }
+        return write!(f, "{sign}{}.{:0width$}", units / scale, units % scale, width = DECIMALS as usize);
+    }
+}
+
+// Read "12.34", "-0.5" or "12". More places than DECIMALS are an error
+// rather than being rounded away.
+impl<const DECIMALS: u32> FromStr for Fixed<DECIMALS> {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Error> {
+        let bad = || Error::ParseError {
+            input: text.to_string(),
+            expected: format!("number with at most {DECIMALS} decimal places"),
+        };
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let all_digits = whole.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit());
+        if whole.is_empty() || fraction.len() > DECIMALS as usize || !all_digits {
+            return Err(bad());
+        }
+        let padded = format!("{fraction:0<width$}", width = DECIMALS as usize);
+        let whole: i64 = whole.parse().map_err(|_| bad())?;
+        let fraction: i64 = if padded.is_empty() { 0 } else { padded.parse().map_err(|_| bad())? };
+        let units = whole
+            .checked_mul(Self::SCALE)
+            .and_then(|units| units.checked_add(fraction))
+            .ok_or_else(bad)?;
+        return Ok(Fixed(if negative { -units } else { units }));
+    }
+}
+
+// Fixed values are recorded as floats. The count of units converts exactly
+// below 2^53, but dividing by the scale rounds, so the recorded decimal is
+// only the nearest float: 0.10 comes out as 0.1000000000000000055...
+impl<const DECIMALS: u32> From<Fixed<DECIMALS>> for Value {
+    fn from(value: Fixed<DECIMALS>) -> Self {
+        return Value::Float(value.to_f64());
+    }
+}
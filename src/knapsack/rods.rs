@@ -0,0 +1,146 @@
+use std::cmp::Reverse;
+
+use crate::error::Result;
+use crate::metrics::{Counter, Metrics};
+
+use super::{check_instance, copy_items, fits, incumbent, Item, Number};
+
+
+
+// Build the items' block lists.
+pub fn make_block_lists<V: Number, W: Number>(items: &mut Vec<Item<V, W>>) {
+    let mut id;
+    for i in 0..items.len() {
+        items[i].block_list = Vec::new();
+        for j in 0..items.len() {
+            if i != j {
+                if items[i].value >= items[j].value && items[i].weight <= items[j].weight {
+                    id = items[j].id;
+                    items[i].block_list.push(id);
+                }
+            }
+        }
+    }
+}
+
+
+
+// Branch and bound that also skips items dominated by one left out. If the
+// metrics' budget runs out, return the best solution found so far.
+pub fn rods_technique<V: Number, W: Number>(
+    items: &mut Vec<Item<V, W>>,
+    allowed_weight: W,
+    metrics: &Metrics,
+) -> Result<(Vec<Item<V, W>>, V)> {
+    let total_value = check_instance(items, allowed_weight)?;
+    make_block_lists(items);
+    return Ok(do_rods_technique(items, allowed_weight, V::ZERO, V::ZERO, W::ZERO, total_value, 0, metrics));
+}
+
+
+
+fn do_rods_technique<V: Number, W: Number>(
+    items: &mut Vec<Item<V, W>>,
+    allowed_weight: W,
+    mut best_value: V,
+    current_value: V,
+    current_weight: W,
+    remaining_value: V,
+    next_index: i32,
+    metrics: &Metrics,
+) -> (Vec<Item<V, W>>, V) {
+    metrics.count(Counter::Calls);
+    metrics.progress("knapsack", next_index as usize, None);
+    if (next_index as usize) >= items.len() {
+        return (copy_items(items), current_value);
+    } else if metrics.out_of_budget() {
+        return (incumbent(items, next_index), current_value);
+    } else {
+        if current_value + remaining_value <= best_value {
+            metrics.count(Counter::Prunes);
+            return (vec![], current_value);
+        }
+        let (mut included_solution, mut included_value) = (vec![], V::ZERO);
+        if items[next_index as usize].blocked_by == -1 {
+            if fits(current_weight, items[next_index as usize].weight, allowed_weight) {
+                items[next_index as usize].is_selected = true;
+                (included_solution, included_value) = do_rods_technique(
+                    items,
+                    allowed_weight,
+                    best_value,
+                    current_value + items[next_index as usize].value,
+                    current_weight + items[next_index as usize].weight,
+                    remaining_value - items[next_index as usize].value,
+                    next_index + 1,
+                    metrics,
+                );
+                if included_value > best_value {
+                    best_value = included_value;
+                }
+            }
+        }
+        let mut target: usize;
+        for i in 0..items[next_index as usize].block_list.len() {
+            target = items[next_index as usize].block_list[i] as usize;
+            if items[target].blocked_by == -1 {
+                items[target].blocked_by = items[next_index as usize].id;
+            }
+        }
+        items[next_index as usize].is_selected = false;
+        let (excluded_solution, excluded_value) = do_rods_technique(
+            items,
+            allowed_weight,
+            best_value,
+            current_value,
+            current_weight,
+            remaining_value - items[next_index as usize].value,
+            next_index + 1,
+            metrics,
+        );
+        for i in 0..items[next_index as usize].block_list.len() {
+            target = items[next_index as usize].block_list[i] as usize;
+            if items[target].blocked_by == items[next_index as usize].id {
+                items[target].blocked_by = -1;
+            }
+        }
+        if included_value >= excluded_value {
+            return (included_solution, included_value);
+        } else {
+            return (excluded_solution, excluded_value);
+        }
+    }
+}
+
+
+
+pub fn rods_technique_sorted<V: Number, W: Number>(
+    items: &mut Vec<Item<V, W>>,
+    allowed_weight: W,
+    metrics: &Metrics,
+) -> Result<(Vec<Item<V, W>>, V)> {
+    let total_value = check_instance(items, allowed_weight)?;
+    make_block_lists(items);
+
+    // Sort so items with longer blocked lists come first.
+    items.sort_by_key(|item| Reverse(item.block_list.len()));
+
+    // Reset the items' IDs, remembering the originals.
+    let mut original_ids: Vec<i32> = Vec::with_capacity(items.len());
+    for i in 0..items.len() {
+        original_ids.push(items[i].id);
+        items[i].id = i as i32;
+    }
+
+    // Rebuild the blocked lists with the new indices.
+    make_block_lists(items);
+
+    let (mut solution, best_value) =
+        do_rods_technique(items, allowed_weight, V::ZERO, V::ZERO, W::ZERO, total_value, 0, metrics);
+
+    // Give the solution the original IDs back, so callers can tell which
+    // of their items were selected.
+    for i in 0..solution.len() {
+        solution[i].id = original_ids[i];
+    }
+    return Ok((solution, best_value));
+}
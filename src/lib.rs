@@ -0,0 +1,23 @@
+//! Sorting, searching, number theory, RSA, knapsack and backtracking
+//! algorithms. The programs in `src/bin` are thin wrappers over these modules.
+
+pub mod args;
+pub mod backtracking;
+pub mod bench;
+pub mod budget;
+pub mod check;
+pub mod error;
+pub mod ffi;
+pub mod io;
+pub mod knapsack;
+pub mod metrics;
+pub mod number_theory;
+pub mod output;
+pub mod progress;
+pub mod prompt;
+pub mod rng;
+pub mod rsa;
+pub mod searching;
+pub mod sorting;
+pub mod stats;
+pub mod trace;
@@ -0,0 +1,280 @@
+//! Named operation counters and timers that algorithms report into.
+//! Counters are atomic, so one Metrics can be shared by several threads.
+//! A Metrics can also carry a Tracer that records the call tree, a Budget
+//! that the searches check to know when to stop, and a Progress to report
+//! how far they have got.
+
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::budget::{Budget, Outcome, StopReason};
+use crate::output::Record;
+use crate::progress::Progress;
+use crate::trace::Tracer;
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Counter {
+    Calls,
+    Comparisons,
+    Swaps,
+    Probes,
+    Prunes,
+    Moves,
+}
+
+impl Counter {
+    pub const ALL: [Counter; 6] = [
+        Counter::Calls,
+        Counter::Comparisons,
+        Counter::Swaps,
+        Counter::Probes,
+        Counter::Prunes,
+        Counter::Moves,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Counter::Calls => "calls",
+            Counter::Comparisons => "comparisons",
+            Counter::Swaps => "swaps",
+            Counter::Probes => "probes",
+            Counter::Prunes => "prunes",
+            Counter::Moves => "moves",
+        };
+    }
+}
+
+
+
+#[derive(Default)]
+pub struct Metrics {
+    counts: [AtomicU64; Counter::ALL.len()],
+    // Total time spent in each named timer, in the order first used.
+    timers: Mutex<Vec<(&'static str, Duration)>>,
+    // Present only when tracing, so untraced runs never lock it.
+    tracer: Option<Mutex<Tracer>>,
+    budget: Option<Budget>,
+    // The StopReason's position in StopReason::ALL plus one once the budget
+    // has run out, or zero.
+    stopped: AtomicU8,
+    // When the search first checked a budget with a time limit since the
+    // Metrics was made or last reset. Only such budgets lock it.
+    started: Mutex<Option<Instant>>,
+    progress: Option<Mutex<Progress>>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        return Metrics::default();
+    }
+
+    // Return a Metrics that also records the call tree of traced algorithms.
+    pub fn with_tracer(tracer: Tracer) -> Self {
+        return Metrics {
+            tracer: Some(Mutex::new(tracer)),
+            ..Metrics::default()
+        };
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        return self.tracer.is_some();
+    }
+
+    // Limit the searches run with this Metrics. Calls are counted from when
+    // the Metrics was made or last reset, and time from the search's first
+    // check after that.
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = Some(budget);
+        return self;
+    }
+
+    // Return true if the search should stop. Once the budget has run out
+    // this keeps returning true, so the whole search unwinds.
+    pub fn out_of_budget(&self) -> bool {
+        let budget = match &self.budget {
+            Some(budget) => budget,
+            None => return false,
+        };
+        if self.stopped.load(Ordering::Relaxed) != 0 {
+            return true;
+        }
+        let started = budget
+            .time_limit
+            .map(|_| *self.started.lock().unwrap().get_or_insert_with(Instant::now));
+        return match budget.check(self.get(Counter::Calls), started) {
+            Some(reason) => {
+                let index = StopReason::ALL.iter().position(|r| *r == reason).unwrap();
+                self.stopped.store(index as u8 + 1, Ordering::Relaxed);
+                self.note(|| format!("stopped: {}", reason.name()));
+                true
+            }
+            None => false,
+        };
+    }
+
+    // Return why the budget ran out, if it did.
+    pub fn stop_reason(&self) -> Option<StopReason> {
+        return match self.stopped.load(Ordering::Relaxed) {
+            0 => None,
+            n => Some(StopReason::ALL[n as usize - 1]),
+        };
+    }
+
+    // Send reports on how far the routines run with this Metrics have got.
+    pub fn with_progress(mut self, progress: Progress) -> Self {
+        self.progress = Some(Mutex::new(progress));
+        return self;
+    }
+
+    // Tell the progress observer, if any, how far task has got. The calls
+    // counted so far are its nodes.
+    pub fn progress(&self, task: &'static str, depth: usize, fraction: Option<f64>) {
+        if let Some(progress) = &self.progress {
+            progress.lock().unwrap().report(task, self.get(Counter::Calls), depth, fraction);
+        }
+    }
+
+    // Send the progress observer its last report for this run.
+    pub fn finish_progress(&self) {
+        if let Some(progress) = &self.progress {
+            let complete = self.stop_reason().is_none();
+            progress.lock().unwrap().finish(self.get(Counter::Calls), complete);
+        }
+    }
+
+    // Pair a search's result with the reason it stopped early, if it did.
+    pub fn outcome<T>(&self, result: T) -> Outcome<T> {
+        return Outcome {
+            result,
+            stopped: self.stop_reason(),
+        };
+    }
+
+    // Run the body of a recursive call. When tracing, record the call with
+    // the label and the result as shown, nested under the enclosing call.
+    // The label and result are only formatted when tracing.
+    pub fn trace<T>(
+        &self,
+        label: impl FnOnce() -> String,
+        body: impl FnOnce() -> T,
+        show: impl FnOnce(&T) -> String,
+    ) -> T {
+        let tracer = match &self.tracer {
+            Some(tracer) => tracer,
+            None => return body(),
+        };
+        tracer.lock().unwrap().enter(label());
+        let result = body();
+        tracer.lock().unwrap().exit(show(&result));
+        return result;
+    }
+
+    // Note a decision, such as a prune, on the current traced call.
+    pub fn note(&self, note: impl FnOnce() -> String) {
+        if let Some(tracer) = &self.tracer {
+            tracer.lock().unwrap().note(note());
+        }
+    }
+
+    // Return the tracer, if any, leaving an empty one of the same capacity.
+    pub fn take_tracer(&self) -> Option<Tracer> {
+        return self.tracer.as_ref().map(|tracer| {
+            let mut tracer = tracer.lock().unwrap();
+            let max_nodes = tracer.max_nodes;
+            std::mem::replace(&mut *tracer, Tracer::new(max_nodes))
+        });
+    }
+
+    // Add one to a counter.
+    pub fn count(&self, counter: Counter) {
+        self.add(counter, 1);
+    }
+
+    pub fn add(&self, counter: Counter, n: u64) {
+        self.counts[counter as usize].fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn get(&self, counter: Counter) -> u64 {
+        return self.counts[counter as usize].load(Ordering::Relaxed);
+    }
+
+    // Run the closure and add its running time to the named timer.
+    pub fn time<T>(&self, name: &'static str, run: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = run();
+        self.add_time(name, start.elapsed());
+        return result;
+    }
+
+    pub fn add_time(&self, name: &'static str, duration: Duration) {
+        let mut timers = self.timers.lock().unwrap();
+        match timers.iter_mut().find(|(timer, _)| *timer == name) {
+            Some((_, total)) => *total += duration,
+            None => timers.push((name, duration)),
+        }
+    }
+
+    // Return the total time of the named timer, zero if it was never used.
+    pub fn elapsed(&self, name: &str) -> Duration {
+        let timers = self.timers.lock().unwrap();
+        return timers
+            .iter()
+            .find(|(timer, _)| *timer == name)
+            .map(|(_, total)| *total)
+            .unwrap_or(Duration::ZERO);
+    }
+
+    pub fn reset(&self) {
+        for count in self.counts.iter() {
+            count.store(0, Ordering::Relaxed);
+        }
+        self.timers.lock().unwrap().clear();
+        self.stopped.store(0, Ordering::Relaxed);
+        *self.started.lock().unwrap() = None;
+    }
+
+    // Return the counters that are not zero.
+    pub fn counters(&self) -> Vec<(&'static str, u64)> {
+        return Counter::ALL
+            .iter()
+            .map(|counter| (counter.name(), self.get(*counter)))
+            .filter(|(_, value)| *value > 0)
+            .collect();
+    }
+
+    pub fn timers(&self) -> Vec<(&'static str, Duration)> {
+        return self.timers.lock().unwrap().clone();
+    }
+
+    // Describe the non-zero counters and the timers on one line,
+    // e.g. "calls=61 prunes=12 elapsed=13.2µs".
+    pub fn report(&self) -> String {
+        let mut parts: Vec<String> = self
+            .counters()
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect();
+        for (name, duration) in self.timers() {
+            parts.push(format!("{name}={duration:?}"));
+        }
+        return parts.join(" ");
+    }
+
+    // Add every counter, zero or not, and the timers to a record, so records
+    // of the same kind always have the same columns. Runs with a budget
+    // also get a "stopped" column: the StopReason's name, or "no".
+    pub fn add_to(&self, record: &mut Record) {
+        for counter in Counter::ALL {
+            record.push(counter.name(), self.get(counter));
+        }
+        for (name, duration) in self.timers() {
+            record.push(name, duration);
+        }
+        if self.budget.is_some() {
+            record.push("stopped", self.stop_reason().map_or("no", |reason| reason.name()));
+        }
+    }
+}
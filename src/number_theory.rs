@@ -0,0 +1,385 @@
+//! Factorials, Fibonacci numbers, GCD/LCM, fast exponentiation, primes and factoring.
+
+use crate::error::{Error, Result};
+use crate::metrics::{Counter, Metrics};
+use crate::rng::{RandomSource, Sampling};
+
+// find_factors_with_progress reports once per this many trial factors, as a
+// report for each would cost more than the division it describes.
+const PROGRESS_STEP: i64 = 1 << 16;
+
+// find_prime gives up after this many composite candidates. Among 9-digit
+// numbers one odd number in ten is prime, so a range with primes in it
+// practically never runs out.
+const MAX_PRIME_CANDIDATES: usize = 10_000;
+
+
+
+// Return n!, which only fits in an i64 for n <= 20.
+pub fn factorial(n: i64) -> Result<i64> {
+    if n < 0 {
+        return Err(Error::InvalidArgument(format!("factorial of negative number {n}")));
+    }
+    if n == 0 {
+        return Ok(1);
+    }
+    return n
+        .checked_mul(factorial(n - 1)?)
+        .ok_or_else(|| Error::Overflow(format!("{n}!")));
+}
+
+
+
+pub fn fibonacci(n: i64) -> i64 {
+    return fibonacci_traced(n, &Metrics::new());
+}
+
+
+
+// Same as fibonacci, but count and trace each call.
+pub fn fibonacci_traced(n: i64, metrics: &Metrics) -> i64 {
+    metrics.count(Counter::Calls);
+    return metrics.trace(
+        || format!("fibonacci({n})"),
+        || {
+            if n < 2 {
+                n
+            } else {
+                fibonacci_traced(n - 1, metrics) + fibonacci_traced(n - 2, metrics)
+            }
+        },
+        |fibn| fibn.to_string(),
+    );
+}
+
+
+
+pub fn fibonacci_on_the_fly(values: &mut Vec<i64>, n: i64) -> i64 {
+    return fibonacci_on_the_fly_traced(values, n, &Metrics::new());
+}
+
+
+
+// Same as fibonacci_on_the_fly, but count and trace each call. Calls
+// answered from the table are noted as memo hits.
+pub fn fibonacci_on_the_fly_traced(values: &mut Vec<i64>, n: i64, metrics: &Metrics) -> i64 {
+    metrics.count(Counter::Calls);
+    return metrics.trace(
+        || format!("fibonacci_on_the_fly({n})"),
+        || {
+            if (n as usize) < values.len() {
+                metrics.note(|| String::from("memo hit"));
+                return values[n as usize];
+            } else {
+                let fibn = fibonacci_on_the_fly_traced(values, n - 1, metrics)
+                    + fibonacci_on_the_fly_traced(values, n - 2, metrics);
+                values.push(fibn);
+                return fibn;
+            }
+        },
+        |fibn| fibn.to_string(),
+    );
+}
+
+
+
+// The greatest common divisor, which is never negative. gcd(0, 0) is 0.
+// The GCD of 0 or i64::MIN with i64::MIN is 2^63, which does not fit in
+// an i64, so that fails with Overflow.
+pub fn gcd(a: i64, b: i64) -> Result<i64> {
+    return gcd_traced(a, b, &Metrics::new());
+}
+
+
+
+// Same as gcd, but count and trace each call.
+pub fn gcd_traced(a: i64, b: i64, metrics: &Metrics) -> Result<i64> {
+    metrics.count(Counter::Calls);
+    return metrics.trace(
+        || format!("gcd({a}, {b})"),
+        || {
+            if b == 0 {
+                return a.checked_abs().ok_or_else(|| Error::Overflow(format!("gcd({a}, {b})")));
+            } else {
+                // i64::MIN % -1 overflows, though the remainder is 0.
+                return gcd_traced(b, a.wrapping_rem(b), metrics);
+            }
+        },
+        |gcdv| match gcdv {
+            Ok(gcdv) => gcdv.to_string(),
+            Err(error) => error.to_string(),
+        },
+    );
+}
+
+
+
+// The least common multiple, which is never negative. It is 0 if a or b is.
+pub fn lcm(a: i64, b: i64) -> Result<i64> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+    return lcm_with_gcd(a, b, gcd(a, b)?);
+}
+
+
+
+// Calculate the LCM when the GCD is already known. Fails with Overflow if
+// the LCM does not fit in an i64.
+pub fn lcm_with_gcd(a: i64, b: i64, gcdv: i64) -> Result<i64> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+    if gcdv == 0 {
+        return Err(Error::InvalidArgument(format!("0 is not the GCD of {a} and {b}")));
+    }
+    return a
+        .checked_div(gcdv)
+        .and_then(|quotient| quotient.checked_mul(b))
+        .and_then(|lcm| lcm.checked_abs())
+        .ok_or_else(|| Error::Overflow(format!("lcm({a}, {b})")));
+}
+
+
+
+// Perform fast exponentiation.
+pub fn fast_exp(num: i64, pow: i64) -> Result<i64> {
+    if pow < 0 {
+        return Err(Error::InvalidArgument(format!("negative power {pow}")));
+    }
+    let overflow = || Error::Overflow(format!("{num}^{pow}"));
+    let (mut base, mut rest) = (num, pow);
+    let mut result: i64 = 1;
+    while rest > 0 {
+        if rest & 1 == 1 {
+            result = result.checked_mul(base).ok_or_else(overflow)?;
+        }
+        rest /= 2;
+        // Only square the base if it will be used again.
+        if rest > 0 {
+            base = base.checked_mul(base).ok_or_else(overflow)?;
+        }
+    }
+    return Ok(result);
+}
+
+
+
+// Perform fast exponentiation in a modulus.
+pub fn fast_exp_mod(num: i64, pow: i64, modulus: i64) -> Result<i64> {
+    if modulus <= 0 {
+        return Err(Error::InvalidArgument(format!("modulus {modulus} is not positive")));
+    }
+    if pow < 0 {
+        return Err(Error::InvalidArgument(format!("negative power {pow}")));
+    }
+    return Ok(pow_mod(num, pow, modulus));
+}
+
+
+
+// Calculate num^pow mod modulus for pow >= 0 and modulus > 0, working in
+// i128 so the products cannot overflow.
+fn pow_mod(num: i64, mut pow: i64, modulus: i64) -> i64 {
+    let modulus = modulus as i128;
+    let mut num = (num as i128).rem_euclid(modulus);
+    let mut result: i128 = 1 % modulus;
+    while pow > 0 {
+        if pow & 1 == 1 {
+            result = (result * num) % modulus;
+        }
+        pow /= 2;
+        num = (num * num) % modulus;
+    }
+    return result as i64;
+}
+
+
+
+// Build a sieve of Eratosthenes.
+pub fn sieve_of_eratosthenes(max: usize) -> Vec<bool> {
+    return sieve_of_eratosthenes_with_progress(max, &Metrics::new());
+}
+
+
+
+// Same as sieve_of_eratosthenes, but report progress. Crossing off the
+// multiples of a prime p takes time in proportion to 1 / p, and by Mertens'
+// theorem the sum of 1 / p over the odd primes up to n is about
+// ln ln n + 0.2615 - 0.5, which gives the total.
+pub fn sieve_of_eratosthenes_with_progress(max: usize, metrics: &Metrics) -> Vec<bool> {
+    let mut is_prime: Vec<bool> = Vec::with_capacity(max + 1);
+    is_prime.resize(max + 1, false);
+    if max >= 2 {
+        is_prime[2] = true;
+        for i in (3..=max).step_by(2) {
+            is_prime[i] = true;
+        }
+        let limit = (max as f64).sqrt().ceil() as usize;
+        let total_work = (limit as f64).ln().ln() + 0.2615 - 0.5;
+        let mut work = 0.0;
+        for i in (3..limit).step_by(2) {
+            metrics.progress("sieve", 0, Some((work / total_work).min(1.0)));
+            if is_prime[i] == true {
+                work += 1.0 / i as f64;
+                for j in ((i * i)..=max).step_by(i) {
+                    is_prime[j] = false;
+                }
+            }
+        }
+    }
+    return is_prime;
+}
+
+
+
+// Print out the primes in the sieve.
+pub fn print_sieve(sieve: &Vec<bool>) {
+    if sieve.len() > 2 {
+        print!("2");
+        for i in (3..sieve.len()).step_by(2) {
+            if sieve[i] {
+                print!(" {}", i);
+            }
+        }
+        println!();
+    }
+}
+
+
+
+// Convert the sieve into a vector holding prime numbers.
+pub fn sieve_to_primes(sieve: &Vec<bool>) -> Vec<i64> {
+    let mut primes: Vec<i64> = vec![];
+    if sieve.len() > 2 {
+        primes.push(2);
+        for i in (3..sieve.len()).step_by(2) {
+            if sieve[i] {
+                primes.push(i as i64);
+            }
+        }
+    }
+    return primes;
+}
+
+
+
+// Return the prime factors of num, which must be positive, in increasing
+// order. 1 has none.
+pub fn find_factors(num: i64) -> Result<Vec<i64>> {
+    return find_factors_with_progress(num, &Metrics::new());
+}
+
+
+
+// Same as find_factors, but report progress every PROGRESS_STEP trial
+// factors, as the fraction of those up to the square root tried so far.
+pub fn find_factors_with_progress(mut num: i64, metrics: &Metrics) -> Result<Vec<i64>> {
+    check_factorable(num)?;
+    let mut factors: Vec<i64> = vec![];
+    while num % 2 == 0 {
+        factors.push(2);
+        num /= 2;
+    }
+    let mut factor: i64 = 3;
+    while factor <= num / factor {
+        if factor % PROGRESS_STEP == 1 {
+            metrics.progress("factor", 0, Some(factor as f64 / (num as f64).sqrt()));
+        }
+        while num % factor == 0 {
+            factors.push(factor);
+            num /= factor;
+        }
+        factor += 2;
+    }
+    if num > 1 {
+        factors.push(num);
+    }
+    return Ok(factors);
+}
+
+
+
+// Same as find_factors, but only try the primes from a sieve as factors.
+pub fn find_factors_sieve(primes: &Vec<i64>, mut num: i64) -> Result<Vec<i64>> {
+    check_factorable(num)?;
+    let mut factors: Vec<i64> = vec![];
+    let sqrt = (num as f64).sqrt().ceil() as i64;
+    for factor in primes.iter() {
+        while num % factor == 0 {
+            factors.push(*factor);
+            num /= factor;
+        }
+        if *factor > sqrt || num < *factor {
+            break;
+        }
+    }
+    if num > 1 {
+        factors.push(num);
+    }
+    return Ok(factors);
+}
+
+
+
+// Every multiple of 2 divides 0, so trial division would never finish.
+fn check_factorable(num: i64) -> Result<()> {
+    if num < 1 {
+        return Err(Error::InvalidArgument(format!("cannot factor {num}, which is not positive")));
+    }
+    return Ok(());
+}
+
+
+
+pub fn multiply_vector(factors: &Vec<i64>) -> i64 {
+    let mut product: i64 = 1;
+    for num in factors.iter() {
+        product *= num;
+    }
+    return product;
+}
+
+
+
+// Return true if p passes num_tests Fermat tests with random bases. A prime
+// always passes; numbers below 2 never do, and 2 has no bases to test.
+pub fn is_probably_prime(p: i32, num_tests: i64, prng: &mut impl RandomSource) -> bool {
+    if p < 3 {
+        return p == 2;
+    }
+    let mut n: i32;
+    let mut np: i64;
+    for _ in 0..num_tests {
+        n = prng.range_i32(2, p);
+        np = pow_mod(n as i64, (p - 1) as i64, p as i64);
+        if np != 1 {
+            return false;
+        }
+    }
+    return true;
+}
+
+
+
+// Return a random odd number in [min, max) that passes num_tests Fermat
+// tests. Fails with InvalidArgument if the range holds no odd number, or
+// if MAX_PRIME_CANDIDATES candidates in a row are composite, as they all
+// are when the range holds no odd prime.
+pub fn find_prime(prng: &mut impl RandomSource, min: i32, max: i32, num_tests: i64) -> Result<i64> {
+    // The candidates are first, first + 2, ..., up to below max.
+    let first = min as i64 | 1;
+    if first >= max as i64 {
+        return Err(Error::InvalidArgument(format!("no odd numbers in [{min}, {max})")));
+    }
+    let num_candidates = (max as i64 - first + 1) / 2;
+    for _ in 0..MAX_PRIME_CANDIDATES {
+        let p = first + 2 * prng.range_i64(0, num_candidates);
+        if is_probably_prime(p as i32, num_tests, prng) {
+            return Ok(p);
+        }
+    }
+    return Err(Error::InvalidArgument(format!(
+        "no prime in [{min}, {max}) after {MAX_PRIME_CANDIDATES} tries"
+    )));
+}
@@ -0,0 +1,361 @@
+//! Text, JSON and CSV renderers for the results of a run.
+
+use std::io;
+use std::io::Write;
+use std::time::Duration;
+
+pub const FORMAT_NAMES: [&str; 3] = ["text", "json", "csv"];
+
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    // Look up a format by the name used on the command line.
+    pub fn from_name(name: &str) -> Option<Format> {
+        return match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        };
+    }
+}
+
+
+
+// One field of a record. Lists hold factors, selected item ids or, nested
+// one level, the rows of a board.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    List(Vec<Value>),
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        return Value::Int(value);
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        return Value::Int(value as i64);
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        return Value::Int(value as i64);
+    }
+}
+
+// Numbers too big for an Int are kept exactly as text.
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        return match i64::try_from(value) {
+            Ok(value) => Value::Int(value),
+            Err(_) => Value::Str(value.to_string()),
+        };
+    }
+}
+
+impl From<u128> for Value {
+    fn from(value: u128) -> Self {
+        return match i64::try_from(value) {
+            Ok(value) => Value::Int(value),
+            Err(_) => Value::Str(value.to_string()),
+        };
+    }
+}
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Self {
+        return Value::Int(value as i64);
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        return Value::Float(value);
+    }
+}
+
+// Durations are recorded in seconds.
+impl From<Duration> for Value {
+    fn from(value: Duration) -> Self {
+        return Value::Float(value.as_secs_f64());
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        return Value::Bool(value);
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        return Value::Str(value.to_string());
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        return Value::Str(value);
+    }
+}
+
+impl From<char> for Value {
+    fn from(value: char) -> Self {
+        return Value::Str(value.to_string());
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        return Value::List(values.into_iter().map(|value| value.into()).collect());
+    }
+}
+
+impl<T: Clone + Into<Value>> From<&Vec<T>> for Value {
+    fn from(values: &Vec<T>) -> Self {
+        return Value::List(values.iter().map(|value| value.clone().into()).collect());
+    }
+}
+
+
+
+// The named fields produced by one run, in the order they were added.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub kind: String,
+    pub fields: Vec<(String, Value)>,
+}
+
+impl Record {
+    pub fn new(kind: &str) -> Self {
+        return Record {
+            kind: kind.to_string(),
+            fields: vec![],
+        };
+    }
+
+    // Add a field and return the record, so fields can be chained.
+    pub fn with(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.push(name, value);
+        return self;
+    }
+
+    pub fn push(&mut self, name: &str, value: impl Into<Value>) {
+        self.fields.push((name.to_string(), value.into()));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        return self
+            .fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value);
+    }
+
+    // The CSV column names: the kind followed by every field name.
+    pub fn columns(&self) -> Vec<String> {
+        let mut columns = vec![String::from("kind")];
+        columns.extend(self.fields.iter().map(|(name, _)| name.clone()));
+        return columns;
+    }
+}
+
+
+
+// Render a record as "name: value" lines. Boards get one line per row.
+pub fn render_text(record: &Record) -> String {
+    let mut text = format!("[{}]\n", record.kind);
+    for (name, value) in record.fields.iter() {
+        match value {
+            Value::List(rows) if rows.iter().any(|row| matches!(row, Value::List(_))) => {
+                text.push_str(&format!("{name}:\n"));
+                let width = rows.iter().map(cell_width).max().unwrap_or(0);
+                for row in rows.iter() {
+                    text.push_str(&format!("  {}\n", text_row(row, width)));
+                }
+            }
+            _ => text.push_str(&format!("{name}: {}\n", plain(value))),
+        }
+    }
+    return text;
+}
+
+
+
+// Render a record as a single-line JSON object.
+pub fn render_json(record: &Record) -> String {
+    let mut json = format!("{{\"kind\":{}", json_string(&record.kind));
+    for (name, value) in record.fields.iter() {
+        json.push_str(&format!(",{}:{}", json_string(name), json_value(value)));
+    }
+    json.push('}');
+    return json;
+}
+
+
+
+// Render the CSV header line for a record.
+pub fn render_csv_header(record: &Record) -> String {
+    let columns: Vec<String> = record.columns().iter().map(|name| csv_field(name)).collect();
+    return columns.join(",");
+}
+
+
+
+// Render a record as one CSV line. Lists are space-separated and board
+// rows are separated by '/'.
+pub fn render_csv(record: &Record) -> String {
+    let mut cells = vec![csv_field(&record.kind)];
+    for (_, value) in record.fields.iter() {
+        cells.push(csv_field(&plain(value)));
+    }
+    return cells.join(",");
+}
+
+
+
+// Writes records in one format. CSV output repeats the header whenever
+// the columns change, so records of different kinds can share a stream.
+pub struct Writer<W: Write> {
+    pub format: Format,
+    out: W,
+    columns: Option<Vec<String>>,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(format: Format, out: W) -> Self {
+        return Writer {
+            format,
+            out,
+            columns: None,
+        };
+    }
+
+    pub fn write(&mut self, record: &Record) -> io::Result<()> {
+        match self.format {
+            Format::Text => writeln!(self.out, "{}", render_text(record))?,
+            Format::Json => writeln!(self.out, "{}", render_json(record))?,
+            Format::Csv => {
+                let columns = record.columns();
+                if self.columns.as_ref() != Some(&columns) {
+                    writeln!(self.out, "{}", render_csv_header(record))?;
+                    self.columns = Some(columns);
+                }
+                writeln!(self.out, "{}", render_csv(record))?;
+            }
+        }
+        return self.out.flush();
+    }
+
+    // Return the underlying output.
+    pub fn into_inner(self) -> W {
+        return self.out;
+    }
+}
+
+
+
+// Format a value without any quoting.
+fn plain(value: &Value) -> String {
+    return match value {
+        Value::Int(i) => i.to_string(),
+        Value::Float(f) => f.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Str(s) => s.clone(),
+        Value::List(values) => {
+            let separator = if values.iter().any(|v| matches!(v, Value::List(_))) {
+                "/"
+            } else {
+                " "
+            };
+            let parts: Vec<String> = values.iter().map(plain).collect();
+            parts.join(separator)
+        }
+    };
+}
+
+
+
+// The widest cell in a board row.
+fn cell_width(row: &Value) -> usize {
+    return match row {
+        Value::List(cells) => cells.iter().map(|cell| plain(cell).len()).max().unwrap_or(0),
+        _ => plain(row).len(),
+    };
+}
+
+
+
+// Format a board row with the cells right-aligned to a common width.
+fn text_row(row: &Value, width: usize) -> String {
+    return match row {
+        Value::List(cells) => {
+            let parts: Vec<String> = cells
+                .iter()
+                .map(|cell| format!("{:>width$}", plain(cell)))
+                .collect();
+            parts.join(" ")
+        }
+        _ => plain(row),
+    };
+}
+
+
+
+fn json_value(value: &Value) -> String {
+    return match value {
+        Value::Int(i) => i.to_string(),
+        Value::Float(f) if f.is_finite() => format!("{f:?}"),
+        Value::Float(_) => String::from("null"),
+        Value::Bool(b) => b.to_string(),
+        Value::Str(s) => json_string(s),
+        Value::List(values) => {
+            let parts: Vec<String> = values.iter().map(json_value).collect();
+            format!("[{}]", parts.join(","))
+        }
+    };
+}
+
+
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for ch in s.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    return json;
+}
+
+
+
+// Quote a CSV field if it contains a separator, quote or line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", s.replace('"', "\"\""));
+    }
+    return s.to_string();
+}
@@ -0,0 +1,169 @@
+//! Progress reports from the long-running routines. A Metrics can carry a
+//! Progress, which passes reports on to an Observer no more often than its
+//! interval allows; ProgressBar is an Observer that draws on a terminal.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+// How often the algo command redraws its progress bar.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+const BAR_WIDTH: usize = 30;
+
+
+
+// How far a routine has got.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    // The routine's name, such as "sieve" or "queens".
+    pub task: &'static str,
+    // The calls counted so far.
+    pub nodes: u64,
+    // How deep a search is, or zero for routines that do not recurse.
+    pub depth: usize,
+    // The fraction of the work done, for routines that can tell.
+    pub fraction: Option<f64>,
+    pub elapsed: Duration,
+    // True for the last report of a run.
+    pub done: bool,
+}
+
+
+
+pub trait Observer: Send {
+    fn observe(&mut self, report: &Report);
+}
+
+impl<F: FnMut(&Report) + Send> Observer for F {
+    fn observe(&mut self, report: &Report) {
+        self(report);
+    }
+}
+
+
+
+// Passes reports to an observer, dropping those that come too soon after
+// the last one sent.
+pub struct Progress {
+    observer: Box<dyn Observer>,
+    interval: Duration,
+    start: Instant,
+    last_sent: Option<Instant>,
+    last: Option<Report>,
+}
+
+impl Progress {
+    // Send every report. Use with_interval to send fewer.
+    pub fn new(observer: impl Observer + 'static) -> Self {
+        return Progress {
+            observer: Box::new(observer),
+            interval: Duration::ZERO,
+            start: Instant::now(),
+            last_sent: None,
+            last: None,
+        };
+    }
+
+    // Send at most one report per interval. The first report and the
+    // finishing one are always sent.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        return self;
+    }
+
+    pub fn report(&mut self, task: &'static str, nodes: u64, depth: usize, fraction: Option<f64>) {
+        let now = Instant::now();
+        let report = Report {
+            task,
+            nodes,
+            depth,
+            fraction,
+            elapsed: now - self.start,
+            done: false,
+        };
+        if self.last_sent.is_none_or(|sent| now - sent >= self.interval) {
+            self.observer.observe(&report);
+            self.last_sent = Some(now);
+        }
+        self.last = Some(report);
+    }
+
+    // Send the last report again, marked done, with the final node count.
+    // If the routine finished its work rather than stopping early, its
+    // fraction is filled up to one. Later reports time a new run.
+    pub fn finish(&mut self, nodes: u64, complete: bool) {
+        let mut report = match self.last.take() {
+            Some(report) => report,
+            None => return,
+        };
+        report.nodes = nodes;
+        if complete && report.fraction.is_some() {
+            report.fraction = Some(1.0);
+        }
+        report.elapsed = self.start.elapsed();
+        report.done = true;
+        self.observer.observe(&report);
+        self.start = Instant::now();
+        self.last_sent = None;
+    }
+}
+
+
+
+// Draws reports on one terminal line, redrawn in place:
+//   sieve      [#############.................]  43% 1.2s
+//   queens     18734 nodes, depth 12, 0.8s
+pub struct ProgressBar<W: Write> {
+    out: W,
+    // The length of the line last drawn, so a shorter one can cover it.
+    last_len: usize,
+}
+
+impl ProgressBar<io::Stderr> {
+    pub fn stderr() -> Self {
+        return ProgressBar::new(io::stderr());
+    }
+}
+
+impl<W: Write> ProgressBar<W> {
+    pub fn new(out: W) -> Self {
+        return ProgressBar { out, last_len: 0 };
+    }
+
+    pub fn into_inner(self) -> W {
+        return self.out;
+    }
+}
+
+impl<W: Write + Send> Observer for ProgressBar<W> {
+    fn observe(&mut self, report: &Report) {
+        let line = render(report);
+        let padding = self.last_len.saturating_sub(line.len());
+        let end = if report.done { "\n" } else { "" };
+        // A closed terminal is no reason to stop the work, so ignore errors.
+        let _ = write!(self.out, "\r{line}{}{end}", " ".repeat(padding));
+        let _ = self.out.flush();
+        self.last_len = if report.done { 0 } else { line.len() };
+    }
+}
+
+
+
+// Return one line describing the report.
+pub fn render(report: &Report) -> String {
+    let seconds = report.elapsed.as_secs_f64();
+    return match report.fraction {
+        Some(fraction) => {
+            let fraction = fraction.clamp(0.0, 1.0);
+            let filled = (fraction * BAR_WIDTH as f64).round() as usize;
+            format!(
+                "{:<10} [{}{}] {:>3.0}% {seconds:.1}s",
+                report.task,
+                "#".repeat(filled),
+                ".".repeat(BAR_WIDTH - filled),
+                fraction * 100.0,
+            )
+        }
+        None => format!("{:<10} {} nodes, depth {}, {seconds:.1}s", report.task, report.nodes, report.depth),
+    };
+}
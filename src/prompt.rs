@@ -0,0 +1,278 @@
+//! Typed prompts that check their answers and ask again on bad input.
+//! Answers can come from the keyboard or be replayed from a script file,
+//! and the accepted answers can be recorded as a script for a later replay.
+
+use std::fmt::Display;
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::io::{BufRead, BufReader, Write};
+use std::str::FromStr;
+
+use crate::error::{parse, Error, Result};
+
+// Answers that end the session, as the end of input does.
+pub const QUIT_WORDS: [&str; 2] = ["q", "quit"];
+
+
+
+// A question with an optional inclusive range and a default that is used
+// when the answer is left blank.
+#[derive(Clone, Debug)]
+pub struct Prompt<T> {
+    pub text: String,
+    pub min: Option<T>,
+    pub max: Option<T>,
+    pub default: Option<T>,
+}
+
+impl<T: FromStr + PartialOrd + Display + Clone> Prompt<T> {
+    pub fn new(text: &str) -> Self {
+        return Prompt {
+            text: text.to_string(),
+            min: None,
+            max: None,
+            default: None,
+        };
+    }
+
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        return self;
+    }
+
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        return self;
+    }
+
+    // Accept answers in [min, max].
+    pub fn range(self, min: T, max: T) -> Self {
+        return self.min(min).max(max);
+    }
+
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        return self;
+    }
+
+    // The text shown to the user, with any default in brackets before the
+    // colon, e.g. "# Items [20]: ".
+    pub fn display(&self) -> String {
+        return match &self.default {
+            None => self.text.clone(),
+            Some(default) => {
+                let question = self.text.trim_end().trim_end_matches(':');
+                format!("{question} [{default}]: ")
+            }
+        };
+    }
+
+    // Parse an answer and check that it is in range. The error describes
+    // what was expected.
+    pub fn check(&self, answer: &str) -> Result<T> {
+        let value: T = parse(answer, "number")?;
+        let in_range = self.min.as_ref().is_none_or(|min| value >= *min)
+            && self.max.as_ref().is_none_or(|max| value <= *max);
+        if !in_range {
+            let expected = match (&self.min, &self.max) {
+                (Some(min), Some(max)) => format!("between {min} and {max}"),
+                (Some(min), None) => format!("at least {min}"),
+                (None, Some(max)) => format!("at most {max}"),
+                (None, None) => String::new(),
+            };
+            return Err(Error::InvalidArgument(format!("{value} is out of range, expected {expected}")));
+        }
+        return Ok(value);
+    }
+}
+
+
+
+enum Input {
+    Stdin,
+    // A script, replayed one answer per line.
+    Script(Box<dyn BufRead>),
+}
+
+
+
+// Asks prompts and reads the answers. Besides a value, an answer can be
+// "q" or "quit" to end the session, "!!" to repeat the last answer, "!N"
+// to repeat answer N, or "history" to list the answers so far.
+pub struct Prompter {
+    input: Input,
+    output: Box<dyn Write>,
+    // The accepted answers, in order.
+    pub history: Vec<String>,
+    // Accepted answers are also appended here, so the session can be replayed.
+    record: Option<File>,
+}
+
+impl Prompter {
+    // Read answers from the keyboard.
+    pub fn stdin() -> Self {
+        return Prompter {
+            input: Input::Stdin,
+            output: Box::new(io::stdout()),
+            history: vec![],
+            record: None,
+        };
+    }
+
+    // Replay answers from a reader, echoing each one after its prompt so the
+    // output reads like the original session. Lines starting with '#' are
+    // comments; a blank line takes the default.
+    pub fn from_reader(reader: impl BufRead + 'static) -> Self {
+        return Prompter {
+            input: Input::Script(Box::new(reader)),
+            ..Prompter::stdin()
+        };
+    }
+
+    pub fn from_script(path: &str) -> Result<Self> {
+        let file = File::open(path).map_err(|e| Error::Io(format!("cannot read {path}: {e}")))?;
+        return Ok(Prompter::from_reader(BufReader::new(file)));
+    }
+
+    // Build a prompter that replays the script, if given, or else reads the
+    // keyboard, and that records its answers to the history file, if given.
+    pub fn from_options(script: Option<&str>, history: Option<&str>) -> Result<Self> {
+        let prompter = match script {
+            Some(path) => Prompter::from_script(path)?,
+            None => Prompter::stdin(),
+        };
+        return match history {
+            Some(path) => prompter.record_to(path),
+            None => Ok(prompter),
+        };
+    }
+
+    // Write prompts and messages here instead of stdout.
+    pub fn with_output(mut self, output: Box<dyn Write>) -> Self {
+        self.output = output;
+        return self;
+    }
+
+    // Append every accepted answer to a file, which can later be replayed
+    // with from_script.
+    pub fn record_to(mut self, path: &str) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| Error::Io(format!("cannot write {path}: {e}")))?;
+        self.record = Some(file);
+        return Ok(self);
+    }
+
+    pub fn is_script(&self) -> bool {
+        return matches!(self.input, Input::Script(_));
+    }
+
+    // Ask until the answer parses and is in range. A blank answer takes the
+    // default, if there is one.
+    pub fn ask<T: FromStr + PartialOrd + Display + Clone>(&mut self, prompt: &Prompt<T>) -> Result<T> {
+        loop {
+            let answer = self.line(&prompt.display())?;
+            if answer.is_empty() {
+                if let Some(default) = &prompt.default {
+                    self.accept(&answer)?;
+                    return Ok(default.clone());
+                }
+                self.say("Please enter a value.")?;
+                continue;
+            }
+            match prompt.check(&answer) {
+                Ok(value) => {
+                    self.accept(&answer)?;
+                    return Ok(value);
+                }
+                Err(error) => self.say(&format!("{error}. Please try again."))?,
+            }
+        }
+    }
+
+    // Show the prompt and return the next answer, trimmed, with history
+    // references expanded. The answer is not added to the history until
+    // it is accepted.
+    pub fn line(&mut self, prompt: &str) -> Result<String> {
+        loop {
+            let answer = self.read(prompt)?;
+            if QUIT_WORDS.contains(&answer.as_str()) {
+                return Err(Error::EndOfInput);
+            }
+            if answer == "history" {
+                if self.history.is_empty() {
+                    self.say("No answers yet.")?;
+                    continue;
+                }
+                let listing: Vec<String> = self
+                    .history
+                    .iter()
+                    .enumerate()
+                    .map(|(i, answer)| format!("{:>4}  {answer}", i + 1))
+                    .collect();
+                self.say(&listing.join("\n"))?;
+                continue;
+            }
+            if let Some(reference) = answer.strip_prefix('!') {
+                let index = match reference {
+                    "!" => self.history.len(),
+                    _ => reference.parse::<usize>().unwrap_or(0),
+                };
+                match index.checked_sub(1).and_then(|i| self.history.get(i)) {
+                    Some(recalled) => {
+                        let recalled = recalled.clone();
+                        self.say(&recalled)?;
+                        return Ok(recalled);
+                    }
+                    None => {
+                        self.say(&format!("No answer {answer} in the history."))?;
+                        continue;
+                    }
+                }
+            }
+            return Ok(answer);
+        }
+    }
+
+    // Print the prompt and read one line.
+    fn read(&mut self, prompt: &str) -> Result<String> {
+        let mut line = String::new();
+        match &mut self.input {
+            Input::Stdin => {
+                write!(self.output, "{prompt}")?;
+                self.output.flush()?;
+                if io::stdin().read_line(&mut line)? == 0 {
+                    return Err(Error::EndOfInput);
+                }
+            }
+            Input::Script(reader) => {
+                loop {
+                    line.clear();
+                    if reader.read_line(&mut line)? == 0 {
+                        return Err(Error::EndOfInput);
+                    }
+                    if !line.trim().starts_with('#') {
+                        break;
+                    }
+                }
+                writeln!(self.output, "{prompt}{}", line.trim())?;
+            }
+        }
+        return Ok(line.trim().to_string());
+    }
+
+    fn accept(&mut self, answer: &str) -> Result<()> {
+        self.history.push(answer.to_string());
+        if let Some(record) = &mut self.record {
+            writeln!(record, "{answer}")?;
+        }
+        return Ok(());
+    }
+
+    fn say(&mut self, message: &str) -> Result<()> {
+        writeln!(self.output, "{message}")?;
+        return Ok(());
+    }
+}
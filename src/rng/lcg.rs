@@ -0,0 +1,93 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::{advance_lcg, check_stream_id, RandomSource, Streams};
+use crate::error::Result;
+
+const MULTIPLIER: u64 = 1_103_515_245;
+const INCREMENT: u64 = 12_345;
+
+
+
+// Return a seed taken from the system clock.
+pub fn clock_seed() -> u32 {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_millis();
+    return millis as u32;
+}
+
+
+
+// The 31-bit linear congruential generator the programs have always used.
+#[derive(Clone, Debug)]
+pub struct Prng {
+    pub seed: u32,
+}
+
+impl Default for Prng {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl Prng {
+    // The LCG has period 2^31, so split gives 128 streams of 2^24 steps each.
+    pub const STREAM_LENGTH: u64 = 1 << 24;
+    pub const NUM_STREAMS: u64 = (1 << 31) / Self::STREAM_LENGTH;
+
+    pub fn new() -> Self {
+        let mut prng = Self { seed: 0 };
+        prng.randomize();
+        return prng;
+    }
+
+    // Make a Prng that always produces the same sequence for this seed.
+    pub fn from_seed(seed: u32) -> Self {
+        return Self { seed };
+    }
+
+    pub fn randomize(&mut self) {
+        self.seed = clock_seed();
+    }
+
+    // Advance the generator by k steps in O(log k) time.
+    pub fn jump(&mut self, k: u64) {
+        let state = advance_lcg(self.seed as u64, MULTIPLIER, INCREMENT, k);
+        self.seed = (state % (1 << 31)) as u32;
+    }
+}
+
+impl Streams for Prng {
+    const MAX_STREAM_ID: u64 = Self::NUM_STREAMS - 1;
+
+    // Return a generator that starts stream_id * STREAM_LENGTH steps ahead.
+    fn split(&self, stream_id: u64) -> Result<Self> {
+        check_stream_id::<Self>("the LCG", stream_id)?;
+        let mut prng = self.clone();
+        prng.jump(stream_id * Self::STREAM_LENGTH);
+        return Ok(prng);
+    }
+}
+
+impl RandomSource for Prng {
+    // Return a pseudorandom value in the range [0, 2147483647].
+    // Note that the top bit is always clear.
+    fn next_u32(&mut self) -> u32 {
+        self.seed = self.seed.wrapping_mul(MULTIPLIER as u32).wrapping_add(INCREMENT as u32);
+        self.seed %= 1 << 31;
+        return self.seed;
+    }
+
+    fn output_bits(&self) -> u32 {
+        return 31;
+    }
+
+    // Each step only yields 31 bits, so take 31 + 31 + 2 bits from three steps.
+    fn next_u64(&mut self) -> u64 {
+        let hi = self.next_u32() as u64;
+        let mid = self.next_u32() as u64;
+        let lo = self.next_u32() as u64;
+        return (hi << 33) | (mid << 2) | (lo >> 29);
+    }
+}
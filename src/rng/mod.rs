@@ -0,0 +1,132 @@
+//! Pseudorandom number generators behind a common RandomSource trait.
+
+use crate::error::{Error, Result};
+
+mod lcg;
+mod pcg;
+pub mod quality;
+mod sample;
+mod splitmix;
+mod xoshiro;
+
+pub use lcg::{clock_seed, Prng};
+pub use pcg::Pcg32;
+pub use sample::Sampling;
+pub use splitmix::SplitMix64;
+pub use xoshiro::Xoshiro256StarStar;
+
+// The names make_source accepts.
+pub const SOURCE_NAMES: [&str; 4] = ["lcg", "splitmix64", "xoshiro256**", "pcg32"];
+
+
+
+pub trait RandomSource {
+    // Return the next 32 pseudorandom bits.
+    fn next_u32(&mut self) -> u32;
+
+    // How many of next_u32's low bits are random. The rest are always 0.
+    fn output_bits(&self) -> u32 {
+        return 32;
+    }
+
+    // Return the next 64 pseudorandom bits.
+    fn next_u64(&mut self) -> u64 {
+        let hi = self.next_u32() as u64;
+        let lo = self.next_u32() as u64;
+        return (hi << 32) | lo;
+    }
+
+    // Fill dest with pseudorandom bytes, 8 at a time.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    // Return a pseudorandom value in the range [0.0, 1.0).
+    fn next_f64(&mut self) -> f64 {
+        let f = (self.next_u64() >> 11) as f64;
+        return f / (1u64 << 53) as f64;
+    }
+}
+
+// Generators that can hand out independent, reproducible streams.
+// Stream i is the same no matter when or on which thread it is created.
+// Streams 0 to MAX_STREAM_ID never overlap, and split refuses any other
+// id with InvalidArgument rather than wrap around to a stream in use.
+pub trait Streams: RandomSource + Sized {
+    const MAX_STREAM_ID: u64;
+
+    fn split(&self, stream_id: u64) -> Result<Self>;
+}
+
+// Check that the generator has a stream with this id.
+pub(crate) fn check_stream_id<S: Streams>(name: &str, stream_id: u64) -> Result<()> {
+    if stream_id > S::MAX_STREAM_ID {
+        return Err(Error::InvalidArgument(format!(
+            "{name} has no stream {stream_id}; its streams go up to {}",
+            S::MAX_STREAM_ID
+        )));
+    }
+    return Ok(());
+}
+
+
+
+impl<R: RandomSource + ?Sized> RandomSource for Box<R> {
+    fn next_u32(&mut self) -> u32 {
+        return (**self).next_u32();
+    }
+
+    fn output_bits(&self) -> u32 {
+        return (**self).output_bits();
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        return (**self).next_u64();
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        (**self).fill_bytes(dest);
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        return (**self).next_f64();
+    }
+}
+
+
+
+// Return the state of the LCG x -> mult * x + inc (mod 2^64) after delta steps.
+// The affine map is raised to the delta-th power by squaring, just like
+// fast_exp_mod, so this takes O(log delta) time. Moduli that divide 2^64
+// can reduce the result afterwards.
+pub(crate) fn advance_lcg(state: u64, mult: u64, inc: u64, mut delta: u64) -> u64 {
+    let (mut acc_mult, mut acc_inc) = (1u64, 0u64);
+    let (mut cur_mult, mut cur_inc) = (mult, inc);
+    while delta > 0 {
+        if delta & 1 == 1 {
+            acc_mult = acc_mult.wrapping_mul(cur_mult);
+            acc_inc = acc_inc.wrapping_mul(cur_mult).wrapping_add(cur_inc);
+        }
+        cur_inc = cur_mult.wrapping_add(1).wrapping_mul(cur_inc);
+        cur_mult = cur_mult.wrapping_mul(cur_mult);
+        delta /= 2;
+    }
+    return acc_mult.wrapping_mul(state).wrapping_add(acc_inc);
+}
+
+
+
+// Make the generator with this name, seeded with seed.
+// Return None if the name is not one of SOURCE_NAMES.
+pub fn make_source(name: &str, seed: u64) -> Option<Box<dyn RandomSource>> {
+    return match name {
+        "lcg" => Some(Box::new(Prng::from_seed(seed as u32))),
+        "splitmix64" => Some(Box::new(SplitMix64::from_seed(seed))),
+        "xoshiro256**" => Some(Box::new(Xoshiro256StarStar::from_seed(seed))),
+        "pcg32" => Some(Box::new(Pcg32::from_seed(seed))),
+        _ => None,
+    };
+}
@@ -0,0 +1,63 @@
+use super::{advance_lcg, check_stream_id, RandomSource, Streams};
+use crate::error::Result;
+
+const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+// The stream used when only a seed is given.
+const DEFAULT_STREAM: u64 = 0xda3e_39cb_94b9_5bdb;
+
+
+
+// O'Neill's PCG32 (XSH RR): a 64-bit LCG whose output is permuted down to 32 bits.
+#[derive(Clone, Debug)]
+pub struct Pcg32 {
+    pub state: u64,
+    pub inc: u64,
+}
+
+impl Pcg32 {
+    // Seed the generator like the reference pcg32_srandom(initstate, initseq).
+    // Different initseq values select independent streams.
+    pub fn new(initstate: u64, initseq: u64) -> Self {
+        let mut pcg = Self {
+            state: 0,
+            inc: (initseq << 1) | 1,
+        };
+        pcg.next_u32();
+        pcg.state = pcg.state.wrapping_add(initstate);
+        pcg.next_u32();
+        return pcg;
+    }
+
+    pub fn from_seed(seed: u64) -> Self {
+        return Self::new(seed, DEFAULT_STREAM);
+    }
+
+    // Skip k outputs in O(log k) time, like the reference pcg32_advance.
+    pub fn jump(&mut self, k: u64) {
+        self.state = advance_lcg(self.state, MULTIPLIER, self.inc, k);
+    }
+}
+
+impl Streams for Pcg32 {
+    // PCG has 2^63 streams built in, selected by the increment, whose
+    // top bit is shifted out.
+    const MAX_STREAM_ID: u64 = (1 << 63) - 1;
+
+    // Reseed like pcg32_srandom so streams that share a state do not start
+    // out differing only in bits the output permutation throws away.
+    fn split(&self, stream_id: u64) -> Result<Self> {
+        check_stream_id::<Self>("PCG32", stream_id)?;
+        return Ok(Self::new(self.state, stream_id));
+    }
+}
+
+impl RandomSource for Pcg32 {
+    fn next_u32(&mut self) -> u32 {
+        let oldstate = self.state;
+        self.state = oldstate.wrapping_mul(MULTIPLIER).wrapping_add(self.inc);
+        let xorshifted = (((oldstate >> 18) ^ oldstate) >> 27) as u32;
+        let rot = (oldstate >> 59) as u32;
+        return xorshifted.rotate_right(rot);
+    }
+}
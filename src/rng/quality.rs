@@ -0,0 +1,266 @@
+//! A battery of statistical tests for any RandomSource.
+
+use super::RandomSource;
+use crate::stats::{chi_square_p_value, normal_p_value};
+
+// A test fails when its p-value falls below this.
+pub const ALPHA: f64 = 0.001;
+
+// Samples drawn by each test in run_battery.
+pub const NUM_SAMPLES: usize = 1 << 18;
+
+
+
+pub struct TestResult {
+    pub name: &'static str,
+    pub statistic: f64,
+    pub p_value: f64,
+    pub passed: bool,
+}
+
+impl TestResult {
+    fn new(name: &'static str, statistic: f64, p_value: f64) -> Self {
+        return Self {
+            name,
+            statistic,
+            p_value,
+            passed: p_value >= ALPHA,
+        };
+    }
+}
+
+
+
+// Return a value in [0, 1) built from the raw output, scaled by the
+// number of bits the generator really produces.
+fn unit(rng: &mut (impl RandomSource + ?Sized)) -> f64 {
+    let bits = rng.output_bits();
+    return rng.next_u32() as f64 / (1u64 << bits) as f64;
+}
+
+// Return the top k of the bits the generator produces.
+fn top_bits(rng: &mut (impl RandomSource + ?Sized), k: u32) -> u32 {
+    let bits = rng.output_bits();
+    return rng.next_u32() >> (bits - k);
+}
+
+
+
+// Bucket the top 8 bits of num_samples outputs and compare the
+// counts with a uniform distribution.
+pub fn chi_square_uniformity(rng: &mut (impl RandomSource + ?Sized), num_samples: usize) -> TestResult {
+    let mut counts = [0u64; 256];
+    for _ in 0..num_samples {
+        counts[top_bits(rng, 8) as usize] += 1;
+    }
+    let expected = num_samples as f64 / 256.0;
+    let statistic: f64 = counts
+        .iter()
+        .map(|c| (*c as f64 - expected) * (*c as f64 - expected) / expected)
+        .sum();
+    return TestResult::new("chi-square uniformity", statistic, chi_square_p_value(statistic, 255.0));
+}
+
+
+
+// Measure the lag-1 correlation between successive outputs.
+// Under independence r·√n is approximately standard normal.
+pub fn serial_correlation(rng: &mut (impl RandomSource + ?Sized), num_samples: usize) -> TestResult {
+    let values: Vec<f64> = (0..num_samples).map(|_| unit(rng)).collect();
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let mut numerator = 0.0;
+    let mut denominator = 0.0;
+    for i in 0..values.len() {
+        let d = values[i] - mean;
+        denominator += d * d;
+        if i + 1 < values.len() {
+            numerator += d * (values[i + 1] - mean);
+        }
+    }
+    let r = if denominator > 0.0 { numerator / denominator } else { 1.0 };
+    let z = r * n.sqrt();
+    return TestResult::new("serial correlation", r, normal_p_value(z));
+}
+
+
+
+// Count runs of outputs above and below one half (Wald–Wolfowitz).
+pub fn runs(rng: &mut (impl RandomSource + ?Sized), num_samples: usize) -> TestResult {
+    let mut num_above = 0.0;
+    let mut num_runs = 0.0;
+    let mut last_above: Option<bool> = None;
+    for _ in 0..num_samples {
+        let above = unit(rng) >= 0.5;
+        if above {
+            num_above += 1.0;
+        }
+        if last_above != Some(above) {
+            num_runs += 1.0;
+        }
+        last_above = Some(above);
+    }
+    let n = num_samples as f64;
+    let num_below = n - num_above;
+    if num_above == 0.0 || num_below == 0.0 {
+        return TestResult::new("runs", num_runs, 0.0);
+    }
+    let mean = 2.0 * num_above * num_below / n + 1.0;
+    let variance = (mean - 1.0) * (mean - 2.0) / (n - 1.0);
+    let z = (num_runs - mean) / variance.sqrt();
+    return TestResult::new("runs", z, normal_p_value(z));
+}
+
+
+
+// Knuth's gap test: the number of outputs between successive values
+// in [0, 1/2) should be geometrically distributed.
+pub fn gap(rng: &mut (impl RandomSource + ?Sized), num_samples: usize) -> TestResult {
+    const MAX_GAP: usize = 10;
+    let mut counts = [0u64; MAX_GAP + 1];
+    let mut num_gaps = 0u64;
+    let mut gap = 0usize;
+    for _ in 0..num_samples {
+        if unit(rng) < 0.5 {
+            counts[gap.min(MAX_GAP)] += 1;
+            num_gaps += 1;
+            gap = 0;
+        } else {
+            gap += 1;
+        }
+    }
+    if num_gaps == 0 {
+        return TestResult::new("gap", 0.0, 0.0);
+    }
+    let mut statistic = 0.0;
+    for r in 0..=MAX_GAP {
+        // P(gap = r) = p(1 - p)^r, and P(gap >= t) = (1 - p)^t, with p = 1/2.
+        let probability = 0.5f64.powi(if r < MAX_GAP { r as i32 + 1 } else { MAX_GAP as i32 });
+        let expected = num_gaps as f64 * probability;
+        let d = counts[r] as f64 - expected;
+        statistic += d * d / expected;
+    }
+    return TestResult::new("gap", statistic, chi_square_p_value(statistic, MAX_GAP as f64));
+}
+
+
+
+// Pair up the low 4 bits of successive outputs and compare the counts of
+// the 256 pairs with a uniform distribution. An LCG modulo a power of two
+// repeats its low k bits every 2^k steps, so only 16 of the pairs appear.
+pub fn low_bits(rng: &mut (impl RandomSource + ?Sized), num_samples: usize) -> TestResult {
+    let mut counts = [0u64; 256];
+    let num_pairs = num_samples / 2;
+    for _ in 0..num_pairs {
+        let first = rng.next_u32() & 0xf;
+        let second = rng.next_u32() & 0xf;
+        counts[(first << 4 | second) as usize] += 1;
+    }
+    let expected = num_pairs as f64 / 256.0;
+    let statistic: f64 = counts
+        .iter()
+        .map(|c| (*c as f64 - expected) * (*c as f64 - expected) / expected)
+        .sum();
+    return TestResult::new("low bits", statistic, chi_square_p_value(statistic, 255.0));
+}
+
+
+
+// Marsaglia's birthday spacings test on the low 24 bits of each output.
+// Pick 512 birthdays in a year of 2^24 days; the number of repeated
+// spacings is Poisson with mean 512³ / (4 · 2^24) = 2. Sum it over
+// many years and compare with the Poisson total.
+pub fn birthday_spacings(rng: &mut (impl RandomSource + ?Sized), num_samples: usize) -> TestResult {
+    const NUM_BIRTHDAYS: usize = 512;
+    const LAMBDA: f64 = 2.0;
+    let num_years = (num_samples / NUM_BIRTHDAYS).max(1);
+    let mut total = 0u64;
+    let mut birthdays = vec![0u32; NUM_BIRTHDAYS];
+    let mut spacings = vec![0u32; NUM_BIRTHDAYS];
+    for _ in 0..num_years {
+        for b in birthdays.iter_mut() {
+            *b = rng.next_u32() & 0x00ff_ffff;
+        }
+        birthdays.sort_unstable();
+        spacings[0] = birthdays[0];
+        for i in 1..NUM_BIRTHDAYS {
+            spacings[i] = birthdays[i] - birthdays[i - 1];
+        }
+        spacings.sort_unstable();
+        for i in 1..NUM_BIRTHDAYS {
+            if spacings[i] == spacings[i - 1] {
+                total += 1;
+            }
+        }
+    }
+    let mean = LAMBDA * num_years as f64;
+    let z = (total as f64 - mean) / mean.sqrt();
+    return TestResult::new("birthday spacings", total as f64, normal_p_value(z));
+}
+
+
+
+// Check each bit the generator produces: it should be set half the time
+// and change between successive outputs half the time. Report the worst
+// bit, with a Bonferroni correction for the two checks per bit.
+pub fn bit_frequency(rng: &mut (impl RandomSource + ?Sized), num_samples: usize) -> TestResult {
+    let bits = rng.output_bits() as usize;
+    let mut ones = [0u64; 32];
+    let mut flips = [0u64; 32];
+    let mut last = rng.next_u32();
+    for _ in 0..num_samples {
+        let x = rng.next_u32();
+        let changed = x ^ last;
+        for bit in 0..bits {
+            ones[bit] += ((x >> bit) & 1) as u64;
+            flips[bit] += ((changed >> bit) & 1) as u64;
+        }
+        last = x;
+    }
+    let n = num_samples as f64;
+    let mut worst_z: f64 = 0.0;
+    for bit in 0..bits {
+        for count in [ones[bit], flips[bit]] {
+            let z = (count as f64 - n / 2.0) / (n / 4.0).sqrt();
+            if z.abs() > worst_z.abs() {
+                worst_z = z;
+            }
+        }
+    }
+    let p_value = (normal_p_value(worst_z) * 2.0 * bits as f64).min(1.0);
+    return TestResult::new("bit frequency", worst_z, p_value);
+}
+
+
+
+// Run every test with num_samples draws each.
+pub fn run_battery(rng: &mut (impl RandomSource + ?Sized), num_samples: usize) -> Vec<TestResult> {
+    return vec![
+        chi_square_uniformity(rng, num_samples),
+        serial_correlation(rng, num_samples),
+        runs(rng, num_samples),
+        gap(rng, num_samples),
+        low_bits(rng, num_samples),
+        birthday_spacings(rng, num_samples),
+        bit_frequency(rng, num_samples),
+    ];
+}
+
+
+
+// Print one line per test and a summary line.
+pub fn print_report(name: &str, results: &Vec<TestResult>) {
+    println!("*** {name} ***");
+    for result in results {
+        println!(
+            "{:<22} statistic {:>14.4}  p = {:<10.6} {}",
+            result.name,
+            result.statistic,
+            result.p_value,
+            if result.passed { "PASS" } else { "FAIL" }
+        );
+    }
+    let num_passed = results.iter().filter(|r| r.passed).count();
+    println!("{} of {} tests passed", num_passed, results.len());
+    println!();
+}
@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use super::RandomSource;
+
+
+
+// Exact range sampling, shuffling and non-uniform distributions
+// for every RandomSource. Ranges are half-open: [min, max).
+pub trait Sampling: RandomSource {
+    // Return a uniformly distributed value in the range [0, bound).
+    // This is Lemire's multiply-shift method: draws that would land in
+    // the short final bucket are rejected rather than folded, so no
+    // value is favoured.
+    fn below_u64(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let m = (self.next_u64() as u128) * (bound as u128);
+            if (m as u64) >= threshold {
+                return (m >> 64) as u64;
+            }
+        }
+    }
+
+    // Return a uniformly distributed value in the range [0, bound).
+    // Draw just enough bits to cover bound and reject values past it.
+    // A bound of 1 needs no bits at all.
+    fn below_u128(&mut self, bound: u128) -> u128 {
+        assert!(bound > 0, "empty range");
+        let mask = u128::MAX.checked_shr((bound - 1).leading_zeros()).unwrap_or(0);
+        loop {
+            let x = (((self.next_u64() as u128) << 64) | self.next_u64() as u128) & mask;
+            if x < bound {
+                return x;
+            }
+        }
+    }
+
+    fn range_u64(&mut self, min: u64, max: u64) -> u64 {
+        assert!(min < max, "empty range");
+        return min + self.below_u64(max - min);
+    }
+
+    fn range_u128(&mut self, min: u128, max: u128) -> u128 {
+        assert!(min < max, "empty range");
+        return min + self.below_u128(max - min);
+    }
+
+    fn range_i64(&mut self, min: i64, max: i64) -> i64 {
+        assert!(min < max, "empty range");
+        let span = max.wrapping_sub(min) as u64;
+        return min.wrapping_add(self.below_u64(span) as i64);
+    }
+
+    fn range_i32(&mut self, min: i32, max: i32) -> i32 {
+        return self.range_i64(min as i64, max as i64) as i32;
+    }
+
+    // Shuffle the slice in place with the Fisher–Yates algorithm.
+    fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.below_u64(i as u64 + 1) as usize;
+            slice.swap(i, j);
+        }
+    }
+
+    // Pick k distinct indices from [0, n) with Floyd's algorithm.
+    // Every k-subset is equally likely; the order is not.
+    fn sample(&mut self, n: usize, k: usize) -> Vec<usize> {
+        assert!(k <= n, "cannot sample {k} of {n}");
+        let mut chosen: HashSet<usize> = HashSet::with_capacity(k);
+        let mut result: Vec<usize> = Vec::with_capacity(k);
+        for j in (n - k)..n {
+            let t = self.below_u64(j as u64 + 1) as usize;
+            let pick = if chosen.contains(&t) { j } else { t };
+            chosen.insert(pick);
+            result.push(pick);
+        }
+        return result;
+    }
+
+    // Return index i with probability weights[i] / sum(weights).
+    fn choose_weighted(&mut self, weights: &[f64]) -> usize {
+        assert!(weights.iter().all(|w| *w >= 0.0), "negative weight");
+        let total: f64 = weights.iter().sum();
+        assert!(total > 0.0, "weights must not all be zero");
+        let target = self.next_f64() * total;
+        let mut running = 0.0;
+        for i in 0..weights.len() {
+            running += weights[i];
+            if target < running {
+                return i;
+            }
+        }
+
+        // Rounding can leave target just past the last sum.
+        return weights.iter().rposition(|w| *w > 0.0).unwrap();
+    }
+
+    // Return a normally distributed value using the Marsaglia polar method.
+    fn next_normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        loop {
+            let u = 2.0 * self.next_f64() - 1.0;
+            let v = 2.0 * self.next_f64() - 1.0;
+            let s = u * u + v * v;
+            if s > 0.0 && s < 1.0 {
+                return mean + std_dev * u * (-2.0 * s.ln() / s).sqrt();
+            }
+        }
+    }
+
+    // Return an exponentially distributed value with the given rate (mean 1 / rate).
+    fn next_exponential(&mut self, rate: f64) -> f64 {
+        assert!(rate > 0.0, "rate must be positive");
+        return -(1.0 - self.next_f64()).ln() / rate;
+    }
+}
+
+impl<R: RandomSource + ?Sized> Sampling for R {}
@@ -0,0 +1,52 @@
+use super::{check_stream_id, RandomSource, Streams};
+use crate::error::Result;
+
+const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+
+
+// Steele, Lea and Flood's SplitMix64: a Weyl sequence run through a mixing function.
+#[derive(Clone, Debug)]
+pub struct SplitMix64 {
+    pub state: u64,
+}
+
+impl SplitMix64 {
+    // split gives 2^24 streams of 2^40 outputs each.
+    pub const STREAM_LENGTH: u64 = 1 << 40;
+
+    pub fn from_seed(seed: u64) -> Self {
+        return Self { state: seed };
+    }
+
+    // Skip k outputs. The state is a Weyl sequence, so this is one multiply.
+    pub fn jump(&mut self, k: u64) {
+        self.state = self.state.wrapping_add(k.wrapping_mul(GAMMA));
+    }
+}
+
+impl Streams for SplitMix64 {
+    const MAX_STREAM_ID: u64 = (1 << 24) - 1;
+
+    // Return a generator that starts stream_id * STREAM_LENGTH outputs ahead.
+    fn split(&self, stream_id: u64) -> Result<Self> {
+        check_stream_id::<Self>("SplitMix64", stream_id)?;
+        let mut splitmix = self.clone();
+        splitmix.jump(stream_id * Self::STREAM_LENGTH);
+        return Ok(splitmix);
+    }
+}
+
+impl RandomSource for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        return (self.next_u64() >> 32) as u32;
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GAMMA);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        return z ^ (z >> 31);
+    }
+}
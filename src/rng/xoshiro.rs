@@ -0,0 +1,102 @@
+use super::{check_stream_id, RandomSource, SplitMix64, Streams};
+use crate::error::Result;
+
+// The authors' jump polynomials for 2^128 and 2^192 steps.
+const JUMP: [u64; 4] = [
+    0x180e_c6d3_3cfd_0aba,
+    0xd5a6_1266_f0c9_392c,
+    0xa958_2618_e03f_c9aa,
+    0x39ab_dc45_29b1_661c,
+];
+const LONG_JUMP: [u64; 4] = [
+    0x76e1_5d3e_fefd_cbbf,
+    0xc500_4e44_1c52_2fb3,
+    0x7771_0069_854e_e241,
+    0x3910_9bb0_2acb_e635,
+];
+
+
+
+// Blackman and Vigna's xoshiro256** with 256 bits of state.
+#[derive(Clone, Debug)]
+pub struct Xoshiro256StarStar {
+    pub state: [u64; 4],
+}
+
+impl Xoshiro256StarStar {
+    // Expand a 64-bit seed into the full state with SplitMix64,
+    // as the authors recommend.
+    pub fn from_seed(seed: u64) -> Self {
+        let mut splitmix = SplitMix64::from_seed(seed);
+        let mut state = [0u64; 4];
+        for word in state.iter_mut() {
+            *word = splitmix.next_u64();
+        }
+        return Self { state };
+    }
+
+    // The state must not be all zeros.
+    pub fn from_state(state: [u64; 4]) -> Self {
+        return Self { state };
+    }
+
+    // Advance by 2^128 steps.
+    pub fn jump(&mut self) {
+        self.apply_jump(&JUMP);
+    }
+
+    // Advance by 2^192 steps.
+    pub fn long_jump(&mut self) {
+        self.apply_jump(&LONG_JUMP);
+    }
+
+    // Multiply the state by a jump polynomial over GF(2).
+    fn apply_jump(&mut self, polynomial: &[u64; 4]) {
+        let mut jumped = [0u64; 4];
+        for word in polynomial {
+            for bit in 0..64 {
+                if word & (1u64 << bit) != 0 {
+                    for i in 0..4 {
+                        jumped[i] ^= self.state[i];
+                    }
+                }
+                self.next_u64();
+            }
+        }
+        self.state = jumped;
+    }
+}
+
+impl Streams for Xoshiro256StarStar {
+    // Stream i starts i * 2^128 steps ahead. This takes i jumps, so the
+    // ids stop at 2^16, enough for thread counts.
+    const MAX_STREAM_ID: u64 = (1 << 16) - 1;
+
+    fn split(&self, stream_id: u64) -> Result<Self> {
+        check_stream_id::<Self>("xoshiro256**", stream_id)?;
+        let mut xoshiro = self.clone();
+        for _ in 0..stream_id {
+            xoshiro.jump();
+        }
+        return Ok(xoshiro);
+    }
+}
+
+impl RandomSource for Xoshiro256StarStar {
+    fn next_u32(&mut self) -> u32 {
+        return (self.next_u64() >> 32) as u32;
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        return result;
+    }
+}
@@ -0,0 +1,91 @@
+//! A toy RSA implementation built on the number theory module.
+
+use crate::error::{Error, Result};
+use crate::number_theory::{find_prime, gcd, lcm};
+use crate::rng::{RandomSource, Sampling};
+
+
+
+pub struct RsaKeys {
+    pub p: i64,
+    pub q: i64,
+    pub n: i64,
+    pub lambda_n: i64,
+    pub e: i64,
+    pub d: i64,
+}
+
+
+
+// Calculate Carmichael's totient function λ(n)
+// where n = p * q and p and q are prime.
+pub fn totient(p: i64, q: i64) -> Result<i64> {
+    return lcm(p - 1, q - 1);
+}
+
+
+
+// Pick a random exponent e in the range [3, λ_n)
+// such that gcd(e, λ_n) = 1. λ_n - 1 always qualifies, so
+// there is one whenever λ_n > 3.
+pub fn random_exponent(prng: &mut impl RandomSource, λ_n: i64) -> Result<i64> {
+    if λ_n <= 3 {
+        return Err(Error::InvalidArgument(format!("no exponent in [3, {λ_n})")));
+    }
+    let mut e: i64;
+    loop {
+        e = prng.range_i64(3, λ_n);
+        if gcd(e, λ_n) == Ok(1) {
+            return Ok(e);
+        }
+    }
+}
+
+
+
+// Return the inverse of a modulo n, using the extended Euclidean algorithm.
+pub fn inverse_mod(a: i64, n: i64) -> Result<i64> {
+    if n <= 0 {
+        return Err(Error::InvalidArgument(format!("modulus {n} is not positive")));
+    }
+    let (mut t, mut newt): (i64, i64) = (0, 1);
+    let (mut r, mut newr): (i64, i64) = (n, a.rem_euclid(n));
+    let mut quotient: i64;
+    while newr != 0 {
+        quotient = r / newr;
+        (t, newt) = (newt, t - (quotient * newt));
+        (r, newr) = (newr, r - (quotient * newr));
+    }
+    if r > 1 {
+        return Err(Error::NotInvertible { a, n });
+    }
+    if t < 0 {
+        t += n;
+    }
+    return Ok(t);
+}
+
+
+
+// Pick two distinct primes in [10000, 50000) and derive the key pair from them.
+pub fn generate_keys(prng: &mut impl RandomSource) -> Result<RsaKeys> {
+    let (p, mut q): (i64, i64) = (
+        find_prime(prng, 10000, 50000, 20)?,
+        find_prime(prng, 10000, 50000, 20)?,
+    );
+    while p == q {
+        q = find_prime(prng, 10000, 50000, 20)?;
+    }
+    let n = p * q;
+    let λ_n = totient(p, q)?;
+    let e = random_exponent(prng, λ_n)?;
+    let d = inverse_mod(e, λ_n)?;
+    return Ok(RsaKeys {
+        p,
+        q,
+        n,
+        lambda_n: λ_n,
+        e,
+        d,
+    });
+}
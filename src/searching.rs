@@ -0,0 +1,50 @@
+//! Linear and binary search over vectors of i32.
+
+use crate::error::Result;
+use crate::metrics::{Counter, Metrics};
+
+
+
+// Return the target's location in the vector, or -1 if it is not found.
+// Each test counts as a probe.
+pub fn linear_search(vec: &Vec<i32>, target: i32, metrics: &Metrics) -> i32 {
+    for i in 0..vec.len() {
+        metrics.count(Counter::Probes);
+        if vec[i] == target {
+            return i as i32;
+        }
+    }
+    return -1;
+}
+
+
+
+// Perform binary search on a sorted vector.
+// Return the target's location in the vector, or -1 if it is not found.
+// Each test counts as a probe.
+pub fn binary_search(vec: &Vec<i32>, target: i32, metrics: &Metrics) -> Result<i32> {
+    // An empty vector has no last index to start from.
+    if vec.is_empty() {
+        return Ok(-1);
+    }
+    let (mut lo, mut hi) = (0, vec.len() - 1);
+    let mut index: usize;
+    while lo <= hi {
+        metrics.count(Counter::Probes);
+        index = (lo + hi) / 2;
+        if vec[index] == target {
+            return Ok(index as i32);
+        } else {
+            if vec[index] < target {
+                lo = index + 1;
+            } else {
+                // The target is smaller than everything from index 0 on.
+                if index == 0 {
+                    break;
+                }
+                hi = index - 1;
+            }
+        }
+    }
+    return Ok(-1);
+}
@@ -0,0 +1,43 @@
+use std::cmp::Ordering;
+
+use crate::metrics::{Counter, Metrics};
+
+
+
+// Use bubble sort to sort the vector.
+pub fn bubble_sort<T: Ord>(vec: &mut [T], metrics: &Metrics) {
+    bubble_sort_by(vec, T::cmp, metrics);
+}
+
+
+
+// Bubble sort by the comparator. Equal elements keep their order.
+pub fn bubble_sort_by<T>(vec: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering, metrics: &Metrics) {
+    let (mut num_comparisons, mut num_swaps) = (0, 0);
+    let mut n = vec.len();
+    let mut newn: usize;
+    while n > 1 {
+        // Everything from n on is in its final place.
+        metrics.progress("sort", 0, Some(1.0 - n as f64 / vec.len() as f64));
+        newn = 0;
+        for i in 1usize..n {
+            num_comparisons += 1;
+            if compare(&vec[i - 1], &vec[i]) == Ordering::Greater {
+                vec.swap(i - 1, i);
+                num_swaps += 1;
+                newn = i;
+            }
+        }
+        n = newn;
+    }
+    metrics.add(Counter::Comparisons, num_comparisons);
+    metrics.add(Counter::Swaps, num_swaps);
+}
+
+
+
+// Bubble sort by a key taken from each element, such as a customer's
+// number of purchases. The key is taken again for every comparison.
+pub fn bubble_sort_by_key<T, K: Ord>(vec: &mut [T], mut key: impl FnMut(&T) -> K, metrics: &Metrics) {
+    bubble_sort_by(vec, |a, b| key(a).cmp(&key(b)), metrics);
+}
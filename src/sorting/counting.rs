@@ -0,0 +1,187 @@
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::rng::{RandomSource, Sampling};
+
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Customer {
+    pub id: String,
+    pub num_purchases: i32,
+}
+
+impl fmt::Display for Customer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.id, self.num_purchases)
+    }
+}
+
+
+
+// Make a vector of customers with random num_purchases in the range [0 and max).
+pub fn make_random_customers(prng: &mut impl RandomSource, num_items: i32, max: i32) -> Vec<Customer> {
+    let mut vec: Vec<Customer> = Vec::with_capacity(num_items as usize);
+    for i in 0..num_items {
+        let customer = Customer {
+            id: format!("C{i}"),
+            num_purchases: prng.range_i32(0, max),
+        };
+        vec.push(customer);
+    }
+    return vec;
+}
+
+
+
+// The most counts a counting sort will allocate. Key ranges wider than
+// this are refused: 2^26 counts take 512MB.
+pub const MAX_COUNTS: usize = 1 << 26;
+
+
+
+// Sort the customers by num_purchases, which must be in [0, max).
+pub fn counting_sort(vec: &Vec<Customer>, max: i32) -> Result<Vec<Customer>> {
+    for customer in vec.iter() {
+        if customer.num_purchases < 0 || customer.num_purchases >= max {
+            return Err(Error::KeyOutOfRange {
+                key: customer.num_purchases as i64,
+                max: max as i64,
+            });
+        }
+    }
+    return counting_sort_by_key(vec, |customer| customer.num_purchases as i64);
+}
+
+
+
+// Return the elements sorted by key, keeping elements with equal keys in
+// their order. The keys can be anywhere in i64, but the range from the
+// smallest to the largest must have at most MAX_COUNTS keys. The key is
+// taken three times for each element.
+pub fn counting_sort_by_key<T: Clone>(vec: &[T], key: impl Fn(&T) -> i64) -> Result<Vec<T>> {
+    let order = sorted_order(vec, key)?;
+    return Ok(order.iter().map(|&i| vec[i].clone()).collect());
+}
+
+
+
+// Sort the elements by key in place, as counting_sort_by_key does, by
+// moving them rather than cloning them.
+pub fn counting_sort_by_key_in_place<T>(vec: &mut [T], key: impl Fn(&T) -> i64) -> Result<()> {
+    let order = sorted_order(vec, key)?;
+    apply_order(vec, &order);
+    return Ok(());
+}
+
+
+
+// Sort numbers in [0, max) in place by counting them. Only the counts
+// from the smallest number to the largest are allocated.
+pub fn counting_sort_numbers<T: Copy + Into<i64>>(vec: &mut [T], max: i64) -> Result<()> {
+    for value in vec.iter() {
+        let key: i64 = (*value).into();
+        if key < 0 || key >= max {
+            return Err(Error::KeyOutOfRange { key, max });
+        }
+    }
+    let (min, num_keys) = key_range(vec, |value| (*value).into())?;
+    let unsorted = vec.to_vec();
+    counting_pass(&unsorted, vec, num_keys, |value| ((*value).into() - min) as usize)?;
+    return Ok(());
+}
+
+
+
+// One stable counting pass: copy src into dst ordered by bucket, which
+// must be below num_buckets, keeping the elements of a bucket in their
+// order. Return where each bucket starts in dst, followed by the length.
+// Counting and radix sorts are made of these passes.
+pub fn counting_pass<T: Copy>(
+    src: &[T],
+    dst: &mut [T],
+    num_buckets: usize,
+    mut bucket: impl FnMut(&T) -> usize,
+) -> Result<Vec<usize>> {
+    let mut starts = make_counts(num_buckets + 1)?;
+    for value in src.iter() {
+        starts[bucket(value) + 1] += 1;
+    }
+    for i in 1..starts.len() {
+        starts[i] += starts[i - 1];
+    }
+    // Where the next element of each bucket goes.
+    let mut next = starts.clone();
+    for value in src.iter() {
+        let b = bucket(value);
+        dst[next[b]] = *value;
+        next[b] += 1;
+    }
+    return Ok(starts);
+}
+
+
+
+// Return the indexes of the elements in stably sorted order of key.
+fn sorted_order<T>(vec: &[T], key: impl Fn(&T) -> i64) -> Result<Vec<usize>> {
+    let (min, num_keys) = key_range(vec, &key)?;
+    let indexes: Vec<usize> = (0..vec.len()).collect();
+    let mut order = vec![0; vec.len()];
+    // Wrapping keeps the difference right when it is more than i64::MAX.
+    counting_pass(&indexes, &mut order, num_keys, |&i| key(&vec[i]).wrapping_sub(min) as u64 as usize)?;
+    return Ok(order);
+}
+
+
+
+// Return the smallest key and the number of keys from it to the largest,
+// failing if that is more than MAX_COUNTS.
+fn key_range<T>(vec: &[T], key: impl Fn(&T) -> i64) -> Result<(i64, usize)> {
+    let (mut min, mut max) = (i64::MAX, i64::MIN);
+    for value in vec.iter() {
+        let key = key(value);
+        min = min.min(key);
+        max = max.max(key);
+    }
+    if vec.is_empty() {
+        return Ok((0, 0));
+    }
+    let num_keys = max as i128 - min as i128 + 1;
+    if num_keys > MAX_COUNTS as i128 {
+        return Err(Error::InvalidArgument(format!(
+            "keys from {min} to {max} need {num_keys} counts, more than the {MAX_COUNTS} allowed"
+        )));
+    }
+    return Ok((min, num_keys as usize));
+}
+
+
+
+// Return n zero counts, failing rather than aborting if there isn't memory.
+fn make_counts(n: usize) -> Result<Vec<usize>> {
+    let mut counts: Vec<usize> = vec![];
+    counts
+        .try_reserve_exact(n)
+        .map_err(|_| Error::InvalidArgument(format!("not enough memory for {n} counts")))?;
+    counts.resize(n, 0);
+    return Ok(counts);
+}
+
+
+
+// Rearrange vec so the element at order[j] ends up at j, by following the
+// permutation's cycles with swaps.
+pub(crate) fn apply_order<T>(vec: &mut [T], order: &[usize]) {
+    // Where the element now at i belongs.
+    let mut destination = vec![0; order.len()];
+    for j in 0..order.len() {
+        destination[order[j]] = j;
+    }
+    for i in 0..vec.len() {
+        while destination[i] != i {
+            let d = destination[i];
+            vec.swap(i, d);
+            destination.swap(i, d);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::cmp::Ordering;
+
+use crate::metrics::{Counter, Metrics};
+
+
+
+// Use heapsort to sort the vector.
+pub fn heapsort<T: Ord>(vec: &mut [T], metrics: &Metrics) {
+    heapsort_by(vec, T::cmp, metrics);
+}
+
+
+
+// Heapsort by the comparator: O(n log n) whatever the input, in place.
+// Equal elements may change order.
+pub fn heapsort_by<T>(vec: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering, metrics: &Metrics) {
+    let (mut num_comparisons, mut num_swaps) = (0, 0);
+    let n = vec.len();
+    // Make a max-heap, then move its top to the end one element at a time.
+    for start in (0..n / 2).rev() {
+        sift_down(vec, start, n, &mut compare, &mut num_comparisons, &mut num_swaps);
+    }
+    for end in (1..n).rev() {
+        vec.swap(0, end);
+        num_swaps += 1;
+        sift_down(vec, 0, end, &mut compare, &mut num_comparisons, &mut num_swaps);
+    }
+    metrics.add(Counter::Comparisons, num_comparisons);
+    metrics.add(Counter::Swaps, num_swaps);
+}
+
+
+
+// Heapsort by a key taken from each element. The key is taken again for
+// every comparison.
+pub fn heapsort_by_key<T, K: Ord>(vec: &mut [T], mut key: impl FnMut(&T) -> K, metrics: &Metrics) {
+    heapsort_by(vec, |a, b| key(a).cmp(&key(b)), metrics);
+}
+
+
+
+// Move the element at root down the heap in vec[..end] until neither of
+// its children is greater.
+fn sift_down<T, F: FnMut(&T, &T) -> Ordering>(
+    vec: &mut [T],
+    mut root: usize,
+    end: usize,
+    compare: &mut F,
+    num_comparisons: &mut u64,
+    num_swaps: &mut u64,
+) {
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end {
+            *num_comparisons += 1;
+            if compare(&vec[child], &vec[child + 1]) == Ordering::Less {
+                child += 1;
+            }
+        }
+        *num_comparisons += 1;
+        if compare(&vec[root], &vec[child]) != Ordering::Less {
+            return;
+        }
+        vec.swap(root, child);
+        *num_swaps += 1;
+        root = child;
+    }
+}
@@ -0,0 +1,41 @@
+use std::cmp::Ordering;
+
+use crate::metrics::{Counter, Metrics};
+
+
+
+// Use insertion sort to sort the vector.
+pub fn insertion_sort<T: Ord>(vec: &mut [T], metrics: &Metrics) {
+    insertion_sort_by(vec, T::cmp, metrics);
+}
+
+
+
+// Insertion sort by the comparator. Equal elements keep their order.
+// Quadratic, but the fastest sort for a handful of elements.
+pub fn insertion_sort_by<T>(vec: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering, metrics: &Metrics) {
+    let (mut num_comparisons, mut num_swaps) = (0, 0);
+    for i in 1usize..vec.len() {
+        // Swap element i down until the one before it is no greater.
+        let mut j = i;
+        while j > 0 {
+            num_comparisons += 1;
+            if compare(&vec[j - 1], &vec[j]) != Ordering::Greater {
+                break;
+            }
+            vec.swap(j - 1, j);
+            num_swaps += 1;
+            j -= 1;
+        }
+    }
+    metrics.add(Counter::Comparisons, num_comparisons);
+    metrics.add(Counter::Swaps, num_swaps);
+}
+
+
+
+// Insertion sort by a key taken from each element. The key is taken again
+// for every comparison.
+pub fn insertion_sort_by_key<T, K: Ord>(vec: &mut [T], mut key: impl FnMut(&T) -> K, metrics: &Metrics) {
+    insertion_sort_by(vec, |a, b| key(a).cmp(&key(b)), metrics);
+}
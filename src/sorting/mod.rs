@@ -0,0 +1,64 @@
+//! Sorting algorithms and the random data they are demonstrated on.
+
+mod bubble;
+mod counting;
+mod heap;
+mod insertion;
+mod order;
+mod quick;
+mod radix;
+mod three_way;
+
+pub use bubble::{bubble_sort, bubble_sort_by, bubble_sort_by_key};
+pub use counting::{
+    counting_pass, counting_sort, counting_sort_by_key, counting_sort_by_key_in_place, counting_sort_numbers,
+    make_random_customers, Customer, MAX_COUNTS,
+};
+pub use heap::{heapsort, heapsort_by, heapsort_by_key};
+pub use insertion::{insertion_sort, insertion_sort_by, insertion_sort_by_key};
+pub use order::{descending, is_sorted, is_sorted_by, is_sorted_by_key, reversed};
+pub use quick::{
+    partition, partition_by, quicksort, quicksort_by, quicksort_by_key, quicksort_with, quicksort_with_by,
+    quicksort_with_by_key, Partition,
+};
+pub use radix::{lsd_radix_sort, lsd_radix_sort_by_key, msd_radix_sort, msd_radix_sort_by_key, Digits, RadixKey};
+pub use three_way::{partition_bentley_mcilroy_by, partition_dijkstra_by};
+
+use crate::rng::{RandomSource, Sampling};
+
+
+
+// Make a vector of random i32 values in the range [0 and max).
+// Seed the generator to get the same vector each time.
+pub fn make_random_vec(prng: &mut impl RandomSource, num_items: i32, max: i32) -> Vec<i32> {
+    let mut vec: Vec<i32> = Vec::with_capacity(num_items as usize);
+    for _ in 0..num_items {
+        vec.push(prng.range_i32(0, max));
+    }
+    return vec;
+}
+
+
+
+// Verify that the Vec is sorted.
+pub fn check_sorted<T: Ord>(vec: &[T]) {
+    report_sorted(is_sorted(vec));
+}
+
+
+
+// Verify that the Vec is sorted by the key, such as a customer's number
+// of purchases.
+pub fn check_sorted_by_key<T, K: Ord>(vec: &[T], key: impl FnMut(&T) -> K) {
+    report_sorted(is_sorted_by_key(vec, key));
+}
+
+
+
+fn report_sorted(sorted: bool) {
+    if sorted {
+        println!("The array is sorted");
+    } else {
+        println!("The array is NOT sorted!");
+    }
+}
@@ -0,0 +1,43 @@
+use std::cmp::Ordering;
+
+
+
+// Return true if every element compares less than or equal to the next.
+pub fn is_sorted<T: Ord>(slice: &[T]) -> bool {
+    return is_sorted_by(slice, T::cmp);
+}
+
+
+
+// Return true if no element compares greater than the one after it.
+pub fn is_sorted_by<T>(slice: &[T], mut compare: impl FnMut(&T, &T) -> Ordering) -> bool {
+    for i in 1..slice.len() {
+        if compare(&slice[i - 1], &slice[i]) == Ordering::Greater {
+            return false;
+        }
+    }
+    return true;
+}
+
+
+
+// Return true if the elements' keys are in non-decreasing order.
+pub fn is_sorted_by_key<T, K: Ord>(slice: &[T], mut key: impl FnMut(&T) -> K) -> bool {
+    return is_sorted_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+
+
+// Compare largest first, for sorting into descending order:
+// quicksort_by(&mut vec, descending, &metrics).
+pub fn descending<T: Ord>(a: &T, b: &T) -> Ordering {
+    return b.cmp(a);
+}
+
+
+
+// Return the comparator that orders the other way round from compare.
+// Keys can be reversed with std::cmp::Reverse instead.
+pub fn reversed<T>(mut compare: impl FnMut(&T, &T) -> Ordering) -> impl FnMut(&T, &T) -> Ordering {
+    return move |a: &T, b: &T| compare(b, a);
+}
@@ -0,0 +1,234 @@
+use std::cmp::Ordering;
+
+use crate::metrics::{Counter, Metrics};
+
+use super::heap::heapsort_by;
+use super::insertion::insertion_sort_by;
+use super::three_way::{partition_bentley_mcilroy_by, partition_dijkstra_by};
+
+// Slices this short are finished with insertion sort.
+const INSERTION_CUTOFF: usize = 16;
+
+// Slices this long take the ninther as their pivot.
+const NINTHER_CUTOFF: usize = 128;
+
+
+
+// How quicksort splits a slice around its pivot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Partition {
+    // Two ways: keys equal to the pivot go to its left, so a slice full of
+    // duplicates splits unevenly.
+    Lomuto,
+    // Three ways, keeping keys equal to the pivot together and leaving them
+    // out of both recursive calls, so a slice with a few distinct keys
+    // sorts in linear time.
+    Dijkstra,
+    BentleyMcIlroy,
+}
+
+impl Partition {
+    pub const ALL: [Partition; 3] = [Partition::Lomuto, Partition::Dijkstra, Partition::BentleyMcIlroy];
+
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Partition::Lomuto => "lomuto",
+            Partition::Dijkstra => "dijkstra",
+            Partition::BentleyMcIlroy => "bentley-mcilroy",
+        };
+    }
+}
+
+
+
+pub fn partition<T: Ord>(vec: &mut [T], metrics: &Metrics) -> i32 {
+    return partition_by(vec, &mut T::cmp, metrics);
+}
+
+
+
+// Partition around the last element: everything that compares no greater
+// than it ends up before it. Return the pivot's new index, which is 0 for
+// a slice too short to need partitioning.
+pub fn partition_by<T, F: FnMut(&T, &T) -> Ordering>(vec: &mut [T], compare: &mut F, metrics: &Metrics) -> i32 {
+    if vec.len() < 2 {
+        return 0;
+    }
+    let (lo, hi) = (0, vec.len() - 1);
+    let mut i = (lo as i32) - 1;
+    // The pivot stays at hi until the end, as i and j stay below it.
+    for j in lo..hi {
+        if compare(&vec[j], &vec[hi]) != Ordering::Greater {
+            i += 1;
+            vec.swap(i as usize, j);
+        }
+    }
+    i += 1;
+    vec.swap(i as usize, hi);
+    metrics.add(Counter::Comparisons, (hi - lo) as u64);
+    metrics.add(Counter::Swaps, i as u64 + 1);
+    return i;
+}
+
+
+
+pub fn quicksort<T: Ord>(vec: &mut [T], metrics: &Metrics) {
+    quicksort_by(vec, T::cmp, metrics);
+}
+
+
+
+// Quicksort by the comparator, introsort style: the pivot is a median of
+// three, or of nine for long slices, so sorted and reversed input split
+// evenly; short slices are finished with insertion sort; and if a slice
+// is still being split more than 2·log₂n levels down, heapsort takes over,
+// so the sort is O(n log n) whatever the input. Only the smaller side of
+// each split is sorted recursively, so the stack stays O(log n) deep.
+// Equal elements may change order.
+pub fn quicksort_by<T>(vec: &mut [T], compare: impl FnMut(&T, &T) -> Ordering, metrics: &Metrics) {
+    quicksort_with_by(vec, Partition::Lomuto, compare, metrics);
+}
+
+
+
+// Quicksort by a key taken from each element. The key is taken again for
+// every comparison.
+pub fn quicksort_by_key<T, K: Ord>(vec: &mut [T], mut key: impl FnMut(&T) -> K, metrics: &Metrics) {
+    quicksort_by(vec, |a, b| key(a).cmp(&key(b)), metrics);
+}
+
+
+
+// Quicksort, splitting each slice the given way.
+pub fn quicksort_with<T: Ord>(vec: &mut [T], partition: Partition, metrics: &Metrics) {
+    quicksort_with_by(vec, partition, T::cmp, metrics);
+}
+
+
+
+pub fn quicksort_with_by<T>(
+    vec: &mut [T],
+    partition: Partition,
+    mut compare: impl FnMut(&T, &T) -> Ordering,
+    metrics: &Metrics,
+) {
+    let max_depth = 2 * vec.len().max(1).ilog2() as usize;
+    do_quicksort(vec, 0, max_depth, partition, &mut compare, metrics);
+}
+
+
+
+pub fn quicksort_with_by_key<T, K: Ord>(
+    vec: &mut [T],
+    partition: Partition,
+    mut key: impl FnMut(&T) -> K,
+    metrics: &Metrics,
+) {
+    quicksort_with_by(vec, partition, |a, b| key(a).cmp(&key(b)), metrics);
+}
+
+
+
+// Sort the part of the vector a quicksort call depth levels down was given.
+// Each pass of the loop splits off and recursively sorts the smaller side,
+// then carries on with the larger side one level further down.
+fn do_quicksort<T, F: FnMut(&T, &T) -> Ordering>(
+    mut vec: &mut [T],
+    mut depth: usize,
+    max_depth: usize,
+    partition: Partition,
+    compare: &mut F,
+    metrics: &Metrics,
+) {
+    loop {
+        metrics.count(Counter::Calls);
+        if vec.len() <= INSERTION_CUTOFF {
+            insertion_sort_by(vec, &mut *compare, metrics);
+            return;
+        }
+        if depth > max_depth {
+            heapsort_by(vec, &mut *compare, metrics);
+            return;
+        }
+        metrics.progress("sort", depth, None);
+        // Split vec into keys before the pivot, vec[lt..gt] which are in
+        // their final places, and keys after them.
+        let pivot = choose_pivot(vec, compare, metrics);
+        metrics.count(Counter::Swaps);
+        let (lt, gt) = match partition {
+            Partition::Lomuto => {
+                let hi = vec.len() - 1;
+                vec.swap(pivot, hi);
+                let p = partition_by(vec, compare, metrics) as usize;
+                (p, p + 1)
+            }
+            Partition::Dijkstra => {
+                vec.swap(0, pivot);
+                partition_dijkstra_by(vec, compare, metrics)
+            }
+            Partition::BentleyMcIlroy => {
+                vec.swap(0, pivot);
+                partition_bentley_mcilroy_by(vec, compare, metrics)
+            }
+        };
+
+        let (left, rest) = std::mem::take(&mut vec).split_at_mut(lt);
+        let right = &mut rest[gt - lt..];
+        depth += 1;
+        if left.len() < right.len() {
+            do_quicksort(left, depth, max_depth, partition, compare, metrics);
+            vec = right;
+        } else {
+            do_quicksort(right, depth, max_depth, partition, compare, metrics);
+            vec = left;
+        }
+    }
+}
+
+
+
+// Return the index of a pivot for vec: the median of the first, middle and
+// last elements, or for long slices Tukey's ninther, the median of the
+// medians of three groups of three spread across the slice. do_quicksort
+// only asks for slices longer than INSERTION_CUTOFF.
+fn choose_pivot<T, F: FnMut(&T, &T) -> Ordering>(vec: &[T], compare: &mut F, metrics: &Metrics) -> usize {
+    let mut num_comparisons = 0;
+    let (lo, mid, hi) = (0, vec.len() / 2, vec.len() - 1);
+    let pivot = if vec.len() < NINTHER_CUTOFF {
+        median_of_three(vec, [lo, mid, hi], compare, &mut num_comparisons)
+    } else {
+        let step = vec.len() / 8;
+        let first = median_of_three(vec, [lo, lo + step, lo + 2 * step], compare, &mut num_comparisons);
+        let middle = median_of_three(vec, [mid - step, mid, mid + step], compare, &mut num_comparisons);
+        let last = median_of_three(vec, [hi - 2 * step, hi - step, hi], compare, &mut num_comparisons);
+        median_of_three(vec, [first, middle, last], compare, &mut num_comparisons)
+    };
+    metrics.add(Counter::Comparisons, num_comparisons);
+    return pivot;
+}
+
+
+
+// Return whichever of the three indexes holds the median of their elements.
+fn median_of_three<T, F: FnMut(&T, &T) -> Ordering>(
+    vec: &[T],
+    [a, b, c]: [usize; 3],
+    compare: &mut F,
+    num_comparisons: &mut u64,
+) -> usize {
+    let mut less = |i: usize, j: usize| {
+        *num_comparisons += 1;
+        return compare(&vec[i], &vec[j]) == Ordering::Less;
+    };
+    if less(a, b) {
+        if less(b, c) {
+            return b;
+        }
+        return if less(a, c) { c } else { a };
+    } else {
+        if less(a, c) {
+            return a;
+        }
+        return if less(b, c) { c } else { b };
+    }
+}
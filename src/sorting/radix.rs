@@ -0,0 +1,193 @@
+use crate::error::Result;
+use crate::metrics::{Counter, Metrics};
+
+use super::counting::{apply_order, counting_pass};
+use super::insertion::insertion_sort_by;
+
+// MSD radix sort finishes buckets this small with insertion sort.
+const MSD_CUTOFF: usize = 16;
+
+
+
+// An integer key for LSD radix sort: its bits as an unsigned number that
+// sorts in the same order as the key.
+pub trait RadixKey: Copy {
+    const BITS: u32;
+
+    fn radix_bits(self) -> u64;
+}
+
+macro_rules! impl_unsigned_radix_key {
+    ($($t:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BITS: u32 = <$t>::BITS;
+
+                fn radix_bits(self) -> u64 {
+                    return self as u64;
+                }
+            }
+        )*
+    };
+}
+
+// Flipping the sign bit moves the negative numbers below the positive ones
+// and keeps both in order.
+macro_rules! impl_signed_radix_key {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BITS: u32 = <$t>::BITS;
+
+                fn radix_bits(self) -> u64 {
+                    return (self as $u ^ (1 << (<$t>::BITS - 1))) as u64;
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_radix_key!(u8, u16, u32, u64);
+impl_signed_radix_key!(i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+
+
+
+// How many bits of the key each LSD pass sorts on. Wider digits mean
+// fewer passes but a bigger counts table: a 32-bit key takes four byte
+// passes or three 11-bit ones.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Digits {
+    Bytes,
+    Bits11,
+}
+
+impl Digits {
+    pub const ALL: [Digits; 2] = [Digits::Bytes, Digits::Bits11];
+
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Digits::Bytes => "8",
+            Digits::Bits11 => "11",
+        };
+    }
+
+    pub fn bits(&self) -> u32 {
+        return match self {
+            Digits::Bytes => 8,
+            Digits::Bits11 => 11,
+        };
+    }
+}
+
+
+
+// Sort the integers with LSD radix sort.
+pub fn lsd_radix_sort<K: RadixKey>(vec: &mut [K], digits: Digits, metrics: &Metrics) -> Result<()> {
+    return lsd_radix_sort_by_key(vec, digits, |value| *value, metrics);
+}
+
+
+
+// Sort by an integer key with least significant digit first radix sort:
+// one stable counting pass per digit, from the lowest digit up. A digit
+// that is the same in every key is skipped, so small keys in a wide type
+// cost only the passes their bits need. Equal keys keep their order.
+pub fn lsd_radix_sort_by_key<T: Copy, K: RadixKey>(
+    vec: &mut [T],
+    digits: Digits,
+    key: impl Fn(&T) -> K,
+    metrics: &Metrics,
+) -> Result<()> {
+    let bits = digits.bits();
+    let mask = (1u64 << bits) - 1;
+    // The bits set in some keys but not in others.
+    let (mut any, mut all) = (0u64, u64::MAX);
+    for value in vec.iter() {
+        let bits = key(value).radix_bits();
+        any |= bits;
+        all &= bits;
+    }
+    let varying = any ^ all;
+
+    let num_passes = K::BITS.div_ceil(bits);
+    let mut scratch = vec.to_vec();
+    // True when the keys sorted so far are in scratch rather than vec.
+    let mut in_scratch = false;
+    for pass in 0..num_passes {
+        metrics.progress("sort", 0, Some(pass as f64 / num_passes as f64));
+        let shift = pass * bits;
+        if (varying >> shift) & mask == 0 {
+            continue;
+        }
+        let digit = |value: &T| ((key(value).radix_bits() >> shift) & mask) as usize;
+        if in_scratch {
+            counting_pass(&scratch, vec, 1 << bits, digit)?;
+        } else {
+            counting_pass(vec, &mut scratch, 1 << bits, digit)?;
+        }
+        in_scratch = !in_scratch;
+        metrics.count(Counter::Calls);
+        metrics.add(Counter::Moves, vec.len() as u64);
+    }
+    if in_scratch {
+        vec.copy_from_slice(&scratch);
+        metrics.add(Counter::Moves, vec.len() as u64);
+    }
+    return Ok(());
+}
+
+
+
+// Sort the byte strings with MSD radix sort.
+pub fn msd_radix_sort<S: AsRef<[u8]>>(vec: &mut [S], metrics: &Metrics) -> Result<()> {
+    return msd_radix_sort_by_key(vec, |value| value.as_ref(), metrics);
+}
+
+
+
+// Sort by a byte string key, such as a customer's id, in the order of
+// [u8]'s Ord, with most significant digit first radix sort: a stable
+// counting pass on the first byte splits the keys into buckets, and each
+// bucket is sorted the same way on the next byte. A key that ends comes
+// before all keys it is a prefix of. Equal keys keep their order.
+pub fn msd_radix_sort_by_key<T>(vec: &mut [T], key: impl Fn(&T) -> &[u8], metrics: &Metrics) -> Result<()> {
+    // Sort the elements' indexes, then move the elements into that order.
+    let mut order: Vec<usize> = (0..vec.len()).collect();
+    let mut scratch = order.clone();
+    let key_of = |i: usize| key(&vec[i]);
+    do_msd_radix_sort(&mut order, &mut scratch, 0, &key_of, metrics)?;
+    apply_order(vec, &order);
+    return Ok(());
+}
+
+
+
+// Sort the indexes, whose keys all have the same first depth bytes, by
+// the rest of their keys.
+fn do_msd_radix_sort<'a>(
+    order: &mut [usize],
+    scratch: &mut [usize],
+    depth: usize,
+    key: &impl Fn(usize) -> &'a [u8],
+    metrics: &Metrics,
+) -> Result<()> {
+    metrics.count(Counter::Calls);
+    if order.len() <= MSD_CUTOFF {
+        insertion_sort_by(order, |&a, &b| key(a)[depth..].cmp(&key(b)[depth..]), metrics);
+        return Ok(());
+    }
+    // Bucket 0 holds the keys that end at depth, bucket b + 1 those whose
+    // next byte is b.
+    let bucket = |&i: &usize| key(i).get(depth).map_or(0, |&byte| byte as usize + 1);
+    let starts = counting_pass(order, scratch, 257, bucket)?;
+    order.copy_from_slice(scratch);
+    metrics.add(Counter::Moves, 2 * order.len() as u64);
+    // The keys in bucket 0 are all equal, so only the others need sorting.
+    for b in 1..257 {
+        let (lo, hi) = (starts[b], starts[b + 1]);
+        if hi - lo > 1 {
+            do_msd_radix_sort(&mut order[lo..hi], &mut scratch[lo..hi], depth + 1, key, metrics)?;
+        }
+    }
+    return Ok(());
+}
@@ -0,0 +1,120 @@
+use std::cmp::Ordering;
+
+use crate::metrics::{Counter, Metrics};
+
+
+
+// Partition around the first element into three ranges, Dijkstra's Dutch
+// national flag way: one pass from the left keeps the keys less than the
+// pivot before lt, those equal to it from lt up to i, and moves the
+// greater ones to the end. Return (lt, gt) such that vec[..lt] is less than
+// the pivot, vec[lt..gt] equal to it and vec[gt..] greater.
+pub fn partition_dijkstra_by<T, F: FnMut(&T, &T) -> Ordering>(
+    vec: &mut [T],
+    compare: &mut F,
+    metrics: &Metrics,
+) -> (usize, usize) {
+    let (mut num_comparisons, mut num_swaps) = (0, 0);
+    let (mut lt, mut i, mut gt) = (0, 1, vec.len());
+    // vec[lt] is always a key equal to the pivot, so compare with that.
+    while i < gt {
+        num_comparisons += 1;
+        match compare(&vec[i], &vec[lt]) {
+            Ordering::Less => {
+                vec.swap(lt, i);
+                num_swaps += 1;
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                vec.swap(i, gt);
+                num_swaps += 1;
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    metrics.add(Counter::Comparisons, num_comparisons);
+    metrics.add(Counter::Swaps, num_swaps);
+    return (lt, gt);
+}
+
+
+
+// Partition around the first element into three ranges, the Bentley and
+// McIlroy way: scan in from both ends as Hoare did, parking keys equal to
+// the pivot at the two ends as they are found, then swap the parked keys
+// into the middle. Fewer swaps than Dijkstra's when keys are distinct.
+// Return (lt, gt) as partition_dijkstra_by does.
+pub fn partition_bentley_mcilroy_by<T, F: FnMut(&T, &T) -> Ordering>(
+    vec: &mut [T],
+    compare: &mut F,
+    metrics: &Metrics,
+) -> (usize, usize) {
+    if vec.len() < 2 {
+        return (0, vec.len());
+    }
+    let (mut num_comparisons, mut num_swaps) = (0, 0);
+    let hi = vec.len() - 1;
+    // vec[1..=p] and vec[q..] hold the parked keys equal to the pivot, which
+    // stays at vec[0] until the end; i and j are the two scans.
+    let (mut i, mut j) = (0, hi + 1);
+    let (mut p, mut q) = (0, hi + 1);
+    loop {
+        loop {
+            i += 1;
+            num_comparisons += 1;
+            if compare(&vec[i], &vec[0]) != Ordering::Less || i == hi {
+                break;
+            }
+        }
+        loop {
+            j -= 1;
+            num_comparisons += 1;
+            if compare(&vec[0], &vec[j]) != Ordering::Less || j == 0 {
+                break;
+            }
+        }
+        if i == j {
+            num_comparisons += 1;
+            if compare(&vec[i], &vec[0]) == Ordering::Equal {
+                p += 1;
+                vec.swap(p, i);
+                num_swaps += 1;
+            }
+        }
+        if i >= j {
+            break;
+        }
+        vec.swap(i, j);
+        num_swaps += 1;
+        num_comparisons += 2;
+        if compare(&vec[i], &vec[0]) == Ordering::Equal {
+            p += 1;
+            vec.swap(p, i);
+            num_swaps += 1;
+        }
+        if compare(&vec[j], &vec[0]) == Ordering::Equal {
+            q -= 1;
+            vec.swap(q, j);
+            num_swaps += 1;
+        }
+    }
+
+    // Everything up to j is less than or equal to the pivot and everything
+    // after it greater or equal. Swap the parked keys, the pivot included,
+    // in next to j from both sides.
+    let (mut lt, mut gt) = (j as isize, j + 1);
+    for k in 0..=p {
+        vec.swap(k, lt as usize);
+        lt -= 1;
+    }
+    for k in (q..=hi).rev() {
+        vec.swap(k, gt);
+        gt += 1;
+    }
+    num_swaps += (p + 1 + hi + 1 - q) as u64;
+    metrics.add(Counter::Comparisons, num_comparisons);
+    metrics.add(Counter::Swaps, num_swaps);
+    return ((lt + 1) as usize, gt);
+}
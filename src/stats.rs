@@ -0,0 +1,100 @@
+//! Special functions and p-values used by the statistical code.
+
+use std::f64::consts::PI;
+
+// Lanczos coefficients for g = 7, n = 9.
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+
+
+// Return ln Γ(x) using the Lanczos approximation.
+pub fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        // Reflection formula.
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let mut sum = LANCZOS[0];
+    for i in 1..LANCZOS.len() {
+        sum += LANCZOS[i] / (x + i as f64);
+    }
+    let t = x + 7.5;
+    return 0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln();
+}
+
+
+
+// Return the regularized upper incomplete gamma function Q(a, x).
+// Use the series for P when x < a + 1 and a continued fraction otherwise.
+pub fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let log_prefix = -x + a * x.ln() - ln_gamma(a);
+    if x < a + 1.0 {
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        let mut n = a;
+        for _ in 0..1000 {
+            n += 1.0;
+            term *= x / n;
+            sum += term;
+            if term.abs() < sum.abs() * 1e-15 {
+                break;
+            }
+        }
+        return (1.0 - sum * log_prefix.exp()).max(0.0);
+    }
+
+    // Modified Lentz's method.
+    let tiny = 1e-300;
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / tiny;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..1000 {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        c = b + an / c;
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < 1e-15 {
+            break;
+        }
+    }
+    return (log_prefix.exp() * h).min(1.0);
+}
+
+
+
+// Return the probability that a chi-square variable with df degrees
+// of freedom is at least statistic.
+pub fn chi_square_p_value(statistic: f64, df: f64) -> f64 {
+    return gamma_q(df / 2.0, statistic / 2.0);
+}
+
+
+
+// Return the two-sided p-value of a standard normal z score.
+pub fn normal_p_value(z: f64) -> f64 {
+    // erfc(|z| / √2) = Q(1/2, z² / 2)
+    return gamma_q(0.5, z * z / 2.0);
+}
@@ -0,0 +1,185 @@
+//! Call trees for the recursive algorithms, recorded through Metrics and
+//! exported as an indented text tree or as Graphviz DOT.
+
+use std::fmt::Write;
+
+// Stop recording new calls after this many, so a large search can be traced
+// without running out of memory.
+pub const DEFAULT_MAX_NODES: usize = 100_000;
+
+// Marks a call on the stack that was not recorded.
+const UNRECORDED: usize = usize::MAX;
+
+
+
+pub struct Node {
+    // The call, e.g. "gcd(270, 192)".
+    pub label: String,
+    // The return value, once the call has returned. Empty for calls that
+    // return nothing.
+    pub result: Option<String>,
+    // Decisions made during the call, e.g. "pruned" or "memo hit".
+    pub notes: Vec<String>,
+    pub depth: usize,
+    pub children: Vec<usize>,
+}
+
+
+
+pub struct Tracer {
+    pub nodes: Vec<Node>,
+    // Calls made at the top level, usually just one.
+    pub roots: Vec<usize>,
+    pub max_nodes: usize,
+    // Calls that were not recorded because the tree was full.
+    pub num_dropped: u64,
+    stack: Vec<usize>,
+}
+
+impl Default for Tracer {
+    fn default() -> Self {
+        return Tracer::new(DEFAULT_MAX_NODES);
+    }
+}
+
+impl Tracer {
+    pub fn new(max_nodes: usize) -> Self {
+        return Tracer {
+            nodes: vec![],
+            roots: vec![],
+            max_nodes,
+            num_dropped: 0,
+            stack: vec![],
+        };
+    }
+
+    // Record the start of a call.
+    pub fn enter(&mut self, label: String) {
+        if self.nodes.len() >= self.max_nodes || self.stack.last() == Some(&UNRECORDED) {
+            self.num_dropped += 1;
+            self.stack.push(UNRECORDED);
+            return;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            label,
+            result: None,
+            notes: vec![],
+            depth: self.stack.len(),
+            children: vec![],
+        });
+        match self.stack.last() {
+            Some(parent) => self.nodes[*parent].children.push(index),
+            None => self.roots.push(index),
+        }
+        self.stack.push(index);
+    }
+
+    // Record the return of the innermost call.
+    pub fn exit(&mut self, result: String) {
+        let index = self.stack.pop().expect("exit without enter");
+        if index != UNRECORDED {
+            self.nodes[index].result = Some(result);
+        }
+    }
+
+    // Attach a note to the innermost call.
+    pub fn note(&mut self, note: String) {
+        if let Some(index) = self.stack.last() {
+            if *index != UNRECORDED {
+                self.nodes[*index].notes.push(note);
+            }
+        }
+    }
+
+    // Return the number of calls in the subtree under a node, including it.
+    pub fn subtree_size(&self, index: usize) -> usize {
+        let mut size = 1;
+        for child in self.nodes[index].children.iter() {
+            size += self.subtree_size(*child);
+        }
+        return size;
+    }
+
+    // Return the tree as indented text, two spaces per level. Calls deeper
+    // than max_depth are summarized by a count.
+    pub fn to_text(&self, max_depth: Option<usize>) -> String {
+        let mut text = String::new();
+        for root in self.roots.iter() {
+            self.write_text(&mut text, *root, max_depth);
+        }
+        if self.num_dropped > 0 {
+            writeln!(text, "... {} more calls not recorded", self.num_dropped).unwrap();
+        }
+        return text;
+    }
+
+    fn write_text(&self, text: &mut String, index: usize, max_depth: Option<usize>) {
+        let node = &self.nodes[index];
+        let indent = "  ".repeat(node.depth);
+        write!(text, "{indent}{}", describe(node)).unwrap();
+        text.push('\n');
+        if max_depth == Some(node.depth) && !node.children.is_empty() {
+            let hidden = self.subtree_size(index) - 1;
+            writeln!(text, "{indent}  ... {hidden} calls below depth {}", node.depth).unwrap();
+            return;
+        }
+        for child in node.children.iter() {
+            self.write_text(text, *child, max_depth);
+        }
+    }
+
+    // Return the tree as a Graphviz digraph. Pruned calls are drawn dashed
+    // and red, calls answered from a memo are drawn filled.
+    pub fn to_dot(&self, max_depth: Option<usize>) -> String {
+        let mut dot = String::from("digraph calls {\n    node [shape=box, fontname=\"monospace\"];\n");
+        for root in self.roots.iter() {
+            self.write_dot(&mut dot, *root, max_depth);
+        }
+        dot.push_str("}\n");
+        return dot;
+    }
+
+    fn write_dot(&self, dot: &mut String, index: usize, max_depth: Option<usize>) {
+        let node = &self.nodes[index];
+        let mut style = String::new();
+        if node.notes.iter().any(|note| note.starts_with("pruned")) {
+            style.push_str(", style=dashed, color=red");
+        } else if node.notes.iter().any(|note| note.starts_with("memo")) {
+            style.push_str(", style=filled, fillcolor=lightgrey");
+        }
+        writeln!(dot, "    n{index} [label=\"{}\"{style}];", dot_escape(&describe(node))).unwrap();
+
+        if max_depth == Some(node.depth) && !node.children.is_empty() {
+            let hidden = self.subtree_size(index) - 1;
+            writeln!(dot, "    n{index}_more [label=\"... {hidden} calls\", shape=plaintext];").unwrap();
+            writeln!(dot, "    n{index} -> n{index}_more [style=dotted];").unwrap();
+            return;
+        }
+        for child in node.children.iter() {
+            writeln!(dot, "    n{index} -> n{child};").unwrap();
+            self.write_dot(dot, *child, max_depth);
+        }
+    }
+}
+
+
+
+// "label = result [note, note]"
+fn describe(node: &Node) -> String {
+    let mut text = node.label.clone();
+    if let Some(result) = node.result.as_ref().filter(|result| !result.is_empty()) {
+        text.push_str(" = ");
+        text.push_str(result);
+    }
+    if !node.notes.is_empty() {
+        text.push_str(&format!(" [{}]", node.notes.join(", ")));
+    }
+    return text;
+}
+
+
+
+fn dot_escape(text: &str) -> String {
+    return text.replace('\\', "\\\\").replace('"', "\\\"");
+}
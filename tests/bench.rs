@@ -0,0 +1,121 @@
+// Tests for the benchmark harness.
+
+use std::thread;
+use std::time::Duration;
+
+use algo::bench::{
+    bench, compare, find_case, fits_budget, measure, size_ladder, suite, summarize, write_csv,
+    Case, Settings,
+};
+use algo::error::Error;
+use algo::output::Value;
+
+
+
+fn quick_settings() -> Settings {
+    return Settings {
+        warmups: 1,
+        repetitions: 3,
+        ..Settings::default()
+    };
+}
+
+// A case that sleeps for n milliseconds.
+fn sleeper() -> Case {
+    return Case::new("sleeper", |_, n| {
+        return measure(|| {
+            thread::sleep(Duration::from_millis(n as u64));
+            return Ok(vec![("naps", 1)]);
+        });
+    });
+}
+
+#[test]
+fn summary_statistics() {
+    let summary = summarize(&[4.0, 1.0, 3.0, 2.0]);
+    assert_eq!(summary.min, 1.0);
+    assert_eq!(summary.median, 2.5);
+    assert_eq!(summary.mean, 2.5);
+    assert!((summary.stddev - 1.2909944).abs() < 1e-6);
+    assert_eq!(summary.p95, 4.0);
+
+    let times: Vec<f64> = (1..=100).map(|t| t as f64).collect();
+    assert_eq!(summarize(&times).p95, 95.0);
+    assert_eq!(summarize(&[7.0]).stddev, 0.0);
+}
+
+#[test]
+fn ladder_reaches_both_ends() {
+    assert_eq!(size_ladder(10, 1000, 10.0), vec![10, 100, 1000]);
+    assert_eq!(size_ladder(1, 5, 1.1), vec![1, 2, 3, 4, 5]);
+    assert_eq!(size_ladder(8, 8, 2.0), vec![8]);
+}
+
+#[test]
+fn every_suite_runs_and_keeps_its_counters() {
+    for name in ["sort", "presorted", "reversed", "organ-pipe", "few-keys", "search", "knapsack"] {
+        let cases = suite(name).unwrap();
+        let results = compare(&cases, &[12], &quick_settings()).unwrap();
+        assert_eq!(results.len(), cases.len());
+        for result in results.iter() {
+            assert_eq!(result.repetitions, 3);
+            assert!(!result.over_budget);
+        }
+    }
+    let result = bench(&find_case("knapsack", "exhaustive").unwrap(), 10, &quick_settings()).unwrap();
+    assert_eq!(result.counters, vec![("calls", 2047.0)]);
+    assert!(suite("heap").is_none());
+
+    let settings = Settings {
+        rng: String::from("dice"),
+        ..quick_settings()
+    };
+    let error = Error::InvalidArgument(String::from("unknown generator dice"));
+    assert_eq!(bench(&sleeper(), 1, &settings).err(), Some(error));
+}
+
+#[test]
+fn cases_see_the_same_inputs() {
+    let settings = quick_settings();
+    let a = bench(&find_case("search", "linear").unwrap(), 500, &settings).unwrap();
+    let b = bench(&find_case("search", "linear").unwrap(), 500, &settings).unwrap();
+    assert_eq!(a.counters, b.counters);
+}
+
+#[test]
+fn over_budget_cases_are_dropped() {
+    let settings = Settings {
+        budget: Duration::from_millis(15),
+        ..quick_settings()
+    };
+    let results = compare(&[sleeper()], &[40, 1, 20], &settings).unwrap();
+    let sizes: Vec<usize> = results.iter().map(|result| result.size).collect();
+    assert_eq!(sizes, vec![1, 20]);
+    assert!(results[1].over_budget);
+    assert_eq!(results[1].repetitions, 1);
+
+    assert!(fits_budget(&sleeper(), 10, Duration::from_millis(50)).unwrap());
+    assert!(!fits_budget(&sleeper(), 100, Duration::from_millis(50)).unwrap());
+    assert!(fits_budget(&find_case("knapsack", "dp").unwrap(), 100, Duration::from_secs(1)).unwrap());
+    assert!(!fits_budget(&find_case("knapsack", "exhaustive").unwrap(), 60, Duration::from_millis(50)).unwrap());
+}
+
+#[test]
+fn csv_has_one_line_per_result() {
+    let results = compare(&suite("sort").unwrap(), &[5, 10], &quick_settings()).unwrap();
+    let mut csv: Vec<u8> = vec![];
+    write_csv(&results, &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 1 + 2 * 9);
+    assert_eq!(
+        lines[0],
+        "kind,name,size,reps,min,median,mean,stddev,p95,calls,comparisons,swaps,probes,prunes,moves,over_budget"
+    );
+    assert!(lines[1].starts_with("bench,bubble,5,3,"));
+
+    // Each counter is a number of its own.
+    let record = results[0].record();
+    assert!(matches!(record.get("comparisons"), Some(Value::Float(mean)) if *mean > 0.0));
+    assert_eq!(record.get("calls"), Some(&Value::Float(0.0)));
+}
@@ -0,0 +1,171 @@
+// Tests for search budgets: a search that runs out of time, nodes or is
+// cancelled stops with a usable partial result, and one that has room
+// to finish returns what it would have without a budget.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use algo::backtracking::{knight_tour, nqueens};
+use algo::budget::{Budget, CancelToken, StopReason};
+use algo::knapsack::{
+    branch_and_bound, exhaustive_search, make_items, rods_technique, rods_technique_sorted,
+    sum_values, sum_weights, Item, Solver,
+};
+use algo::metrics::Metrics;
+use algo::rng::SplitMix64;
+
+const SEED: u64 = 42;
+
+
+
+// Too many items for the exponential solvers to finish.
+fn big_instance() -> (Vec<Item>, i32) {
+    let items = make_items(&mut SplitMix64::from_seed(SEED), 40, 1, 10, 4, 10);
+    let allowed_weight = sum_weights(&items, true).unwrap() / 2;
+    return (items, allowed_weight);
+}
+
+fn solvers() -> Vec<(&'static str, Box<Solver>)> {
+    return vec![
+        ("exhaustive", Box::new(exhaustive_search)),
+        ("bnb", Box::new(branch_and_bound)),
+        ("rods", Box::new(rods_technique)),
+        ("rods-sorted", Box::new(rods_technique_sorted)),
+    ];
+}
+
+fn assert_feasible(name: &str, solution: &Vec<Item>, total_value: i32, allowed_weight: i32) {
+    assert!(sum_weights(solution, false).unwrap() <= allowed_weight, "{name} is too heavy");
+    assert_eq!(sum_values(solution, false).unwrap(), total_value, "{name} misreports its value");
+}
+
+
+
+#[test]
+fn node_limit_keeps_a_feasible_incumbent() {
+    for max_nodes in [0, 50, 500] {
+        for (name, solver) in solvers() {
+            let (mut items, allowed_weight) = big_instance();
+            let metrics = Metrics::new().with_budget(Budget::unlimited().with_max_nodes(max_nodes));
+            let outcome = metrics.outcome(solver(&mut items, allowed_weight, &metrics).unwrap());
+            assert_eq!(outcome.stopped, Some(StopReason::NodeLimit), "{name}");
+            let (solution, total_value) = outcome.result;
+            assert_feasible(name, &solution, total_value, allowed_weight);
+            if max_nodes == 500 {
+                assert!(total_value > 0, "{name} found nothing in {max_nodes} nodes");
+            }
+        }
+    }
+}
+
+#[test]
+fn cancelling_from_another_thread_stops_the_search() {
+    let cancel = CancelToken::new();
+    let canceller = cancel.clone();
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        canceller.cancel();
+    });
+
+    let (mut items, allowed_weight) = big_instance();
+    let metrics = Metrics::new().with_budget(Budget::unlimited().with_cancel(cancel));
+    let (solution, total_value) = exhaustive_search(&mut items, allowed_weight, &metrics).unwrap();
+    handle.join().unwrap();
+    assert_eq!(metrics.stop_reason(), Some(StopReason::Cancelled));
+    assert_feasible("exhaustive", &solution, total_value, allowed_weight);
+}
+
+#[test]
+fn a_passed_deadline_stops_at_once() {
+    let (mut items, allowed_weight) = big_instance();
+    let metrics = Metrics::new().with_budget(Budget::unlimited().with_deadline(Instant::now()));
+    let (solution, total_value) = branch_and_bound(&mut items, allowed_weight, &metrics).unwrap();
+    assert_eq!(metrics.stop_reason(), Some(StopReason::Deadline));
+    assert_eq!((sum_values(&solution, false).unwrap(), total_value), (0, 0));
+
+    // Reset clears the stop along with the counters.
+    metrics.reset();
+    assert_eq!(metrics.stop_reason(), None);
+}
+
+#[test]
+fn time_limits_count_from_the_start_of_the_search() {
+    // A Metrics made well before its search still has its whole time limit.
+    let metrics = Metrics::new().with_budget(Budget::unlimited().with_time_limit(Duration::from_millis(50)));
+    thread::sleep(Duration::from_millis(80));
+    assert!(!metrics.out_of_budget());
+    thread::sleep(Duration::from_millis(80));
+    assert!(metrics.out_of_budget());
+    assert_eq!(metrics.stop_reason(), Some(StopReason::Deadline));
+
+    // A reused Metrics starts its time limit again after a reset.
+    metrics.reset();
+    thread::sleep(Duration::from_millis(80));
+    assert!(!metrics.out_of_budget());
+    assert_eq!(metrics.stop_reason(), None);
+}
+
+#[test]
+fn stopped_queens_leave_a_legal_board() {
+    let mut board = nqueens::make_board(30);
+    let metrics = Metrics::new().with_budget(Budget::unlimited().with_max_nodes(2000));
+    assert!(!nqueens::place_queens_4(&mut board, 0, &metrics));
+    assert_eq!(metrics.stop_reason(), Some(StopReason::NodeLimit));
+    assert!(nqueens::board_is_legal(&board));
+    let num_queens = board.iter().flatten().filter(|square| **square == 'Q').count();
+    assert!(num_queens > 0 && num_queens < 30);
+}
+
+#[test]
+fn stopped_knight_leaves_a_partial_tour() {
+    let mut board = knight_tour::make_board(8, 8);
+    board[0][0] = 0;
+    let metrics = Metrics::new().with_budget(Budget::unlimited().with_max_nodes(1000));
+    let found = knight_tour::find_tour(&mut board, &knight_tour::OFFSETS, 0, 0, 1, true, &metrics);
+    assert!(!found);
+    assert_eq!(metrics.stop_reason(), Some(StopReason::NodeLimit));
+
+    // The visited squares are numbered 0, 1, ... with each a knight's move
+    // from the one before.
+    let mut squares: Vec<(i32, i32, i32)> = vec![];
+    for r in 0..8 {
+        for c in 0..8 {
+            if board[r][c] != knight_tour::UNVISITED {
+                squares.push((board[r][c], r as i32, c as i32));
+            }
+        }
+    }
+    squares.sort();
+    assert!(squares.len() > 1);
+    for i in 0..squares.len() {
+        assert_eq!(squares[i].0, i as i32);
+        if i > 0 {
+            let (dr, dc) = ((squares[i].1 - squares[i - 1].1).abs(), (squares[i].2 - squares[i - 1].2).abs());
+            assert!((dr, dc) == (1, 2) || (dr, dc) == (2, 1), "square {i} is not a knight's move away");
+        }
+    }
+}
+
+#[test]
+fn a_roomy_budget_changes_nothing() {
+    for (name, solver) in solvers() {
+        let mut items = make_items(&mut SplitMix64::from_seed(SEED), 12, 1, 10, 4, 10);
+        let allowed_weight = sum_weights(&items, true).unwrap() / 2;
+        let (_, expected) = solver(&mut items, allowed_weight, &Metrics::new()).unwrap();
+
+        let budget = Budget::unlimited()
+            .with_time_limit(Duration::from_secs(60))
+            .with_max_nodes(1_000_000)
+            .with_cancel(CancelToken::new());
+        let metrics = Metrics::new().with_budget(budget);
+        let outcome = metrics.outcome(solver(&mut items, allowed_weight, &metrics).unwrap());
+        assert!(outcome.is_complete(), "{name}");
+        assert_eq!(outcome.result.1, expected, "{name}");
+    }
+
+    let mut board = nqueens::make_board(4);
+    let metrics = Metrics::new().with_budget(Budget::unlimited());
+    assert!(nqueens::place_queens_1(&mut board, 0, 0, &metrics));
+    assert!(nqueens::board_is_a_solution(&board));
+    assert_eq!(metrics.stop_reason(), None);
+}
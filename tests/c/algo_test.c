@@ -0,0 +1,120 @@
+// Exercises the C interface in include/algo.h. Prints each failed check and
+// exits with status 1 if there were any.
+
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "algo.h"
+
+static int num_checks = 0;
+static int num_failures = 0;
+
+#define CHECK(condition)                                              \
+    do {                                                              \
+        num_checks++;                                                 \
+        if (!(condition)) {                                           \
+            num_failures++;                                           \
+            printf("%s:%d: check failed: %s\n", __FILE__, __LINE__, #condition); \
+        }                                                             \
+    } while (0)
+
+static void test_sorts(void) {
+    int32_t small[] = {5, -3, INT32_MAX, 0, INT32_MIN, 5, 2};
+    CHECK(algo_quicksort_i32(small, 7) == ALGO_OK);
+    for (int i = 1; i < 7; i++) {
+        CHECK(small[i - 1] <= small[i]);
+    }
+
+    int64_t big[] = {INT64_MAX, 3, -7, INT64_MIN, 3};
+    CHECK(algo_quicksort_i64(big, 5) == ALGO_OK);
+    CHECK(big[0] == INT64_MIN && big[1] == -7 && big[2] == 3 && big[3] == 3 && big[4] == INT64_MAX);
+
+    int32_t keys[] = {3, 1, 4, 1, 5, 9, 2, 6};
+    CHECK(algo_counting_sort_i32(keys, 8, 10) == ALGO_OK);
+    for (int i = 1; i < 8; i++) {
+        CHECK(keys[i - 1] <= keys[i]);
+    }
+
+    // Out of range keys fail and leave the data alone.
+    int64_t bad[] = {2, 10, 1};
+    CHECK(algo_counting_sort_i64(bad, 3, 10) == ALGO_ERR_KEY_OUT_OF_RANGE);
+    CHECK(bad[0] == 2 && bad[1] == 10 && bad[2] == 1);
+
+    CHECK(algo_quicksort_i32(NULL, 0) == ALGO_OK);
+    CHECK(algo_quicksort_i32(NULL, 3) == ALGO_ERR_NULL_POINTER);
+}
+
+static void test_number_theory(void) {
+    int64_t result = 0;
+    CHECK(algo_gcd(270, 192, &result) == ALGO_OK && result == 6);
+    CHECK(algo_gcd(-12, 18, &result) == ALGO_OK && result == 6);
+    CHECK(algo_gcd(INT64_MIN, 0, &result) == ALGO_ERR_OVERFLOW);
+    CHECK(algo_lcm(4, 6, &result) == ALGO_OK && result == 12);
+    CHECK(algo_lcm(0, 6, &result) == ALGO_OK && result == 0);
+    CHECK(algo_lcm(INT64_MAX, INT64_MAX - 1, &result) == ALGO_ERR_OVERFLOW);
+    CHECK(algo_fast_exp_mod(4, 13, 497, &result) == ALGO_OK && result == 445);
+    CHECK(algo_fast_exp_mod(4, 13, 0, &result) == ALGO_ERR_INVALID_ARGUMENT);
+    CHECK(algo_inverse_mod(3, 11, &result) == ALGO_OK && result == 4);
+    CHECK(algo_inverse_mod(6, 9, &result) == ALGO_ERR_NOT_INVERTIBLE);
+    CHECK(algo_gcd(1, 2, NULL) == ALGO_ERR_NULL_POINTER);
+
+    bool is_prime = false;
+    CHECK(algo_is_probable_prime(7919, 20, 42, &is_prime) == ALGO_OK && is_prime);
+    CHECK(algo_is_probable_prime(7917, 20, 42, &is_prime) == ALGO_OK && !is_prime);
+    CHECK(algo_is_probable_prime(2, 20, 42, &is_prime) == ALGO_OK && is_prime);
+    CHECK(algo_is_probable_prime(1, 20, 42, &is_prime) == ALGO_OK && !is_prime);
+    CHECK(algo_is_probable_prime(7, 0, 42, &is_prime) == ALGO_ERR_INVALID_ARGUMENT);
+
+    int64_t *factors = NULL;
+    size_t len = 0;
+    CHECK(algo_find_factors(360, &factors, &len) == ALGO_OK);
+    int64_t expected[] = {2, 2, 2, 3, 3, 5};
+    CHECK(len == 6);
+    for (size_t i = 0; i < len && i < 6; i++) {
+        CHECK(factors[i] == expected[i]);
+    }
+    algo_free_i64(factors, len);
+    CHECK(algo_find_factors(0, &factors, &len) == ALGO_ERR_INVALID_ARGUMENT);
+    algo_free_i64(NULL, 0);
+}
+
+static void test_knapsack(void) {
+    // The best selection is items 0, 2, 3 and 8, worth 28 and weighing 15.
+    int32_t values[] = {9, 1, 6, 5, 2, 3, 1, 2, 8};
+    int32_t weights[] = {5, 4, 2, 4, 6, 5, 3, 7, 4};
+    int32_t solvers[] = {
+        ALGO_KNAPSACK_EXHAUSTIVE, ALGO_KNAPSACK_BRANCH_AND_BOUND, ALGO_KNAPSACK_RODS,
+        ALGO_KNAPSACK_RODS_SORTED, ALGO_KNAPSACK_DYNAMIC_PROGRAMMING,
+    };
+    for (int s = 0; s < 5; s++) {
+        uint8_t selected[2] = {0xff, 0xff};
+        int32_t total_value = -1;
+        CHECK(algo_knapsack(solvers[s], values, weights, 9, 15, selected, &total_value) == ALGO_OK);
+        CHECK(total_value == 28);
+        int32_t value = 0, weight = 0;
+        for (int i = 0; i < 9; i++) {
+            if (selected[i / 8] & (1 << (i % 8))) {
+                value += values[i];
+                weight += weights[i];
+            }
+        }
+        CHECK(value == 28 && weight <= 15);
+        CHECK((selected[1] & 0xfe) == 0);
+    }
+
+    uint8_t selected[2];
+    int32_t total_value;
+    CHECK(algo_knapsack(99, values, weights, 9, 15, selected, &total_value) == ALGO_ERR_INVALID_ARGUMENT);
+    CHECK(algo_knapsack(ALGO_KNAPSACK_RODS, values, weights, 9, -1, selected, &total_value) == ALGO_ERR_INVALID_ARGUMENT);
+    CHECK(algo_knapsack(ALGO_KNAPSACK_RODS, values, NULL, 9, 15, selected, &total_value) == ALGO_ERR_NULL_POINTER);
+    CHECK(algo_knapsack(ALGO_KNAPSACK_RODS, NULL, NULL, 0, 15, NULL, &total_value) == ALGO_OK && total_value == 0);
+}
+
+int main(void) {
+    test_sorts();
+    test_number_theory();
+    test_knapsack();
+    CHECK(algo_error_message(ALGO_ERR_OVERFLOW) != NULL);
+    printf("%d of %d checks passed\n", num_checks - num_failures, num_checks);
+    return num_failures == 0 ? 0 : 1;
+}
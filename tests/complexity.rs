@@ -0,0 +1,102 @@
+// Tests for the empirical complexity estimator.
+
+use algo::bench::complexity::{estimate, exponential_base, fit_all, power_exponent, Model};
+use algo::bench::{find_case, Settings};
+
+
+
+#[test]
+fn exact_data_picks_its_own_model() {
+    let sizes: Vec<usize> = vec![4, 8, 16, 32, 64, 128, 256];
+    for model in Model::ALL {
+        let sizes: Vec<usize> = if model == Model::Exponential {
+            (4..12).collect()
+        } else {
+            sizes.clone()
+        };
+        let values: Vec<f64> = sizes.iter().map(|n| 3.0 * model.ln_growth(*n).exp()).collect();
+        let fits = fit_all(&sizes, &values);
+        assert_eq!(fits[0].model, model);
+        assert!(fits[0].rms < 1e-9);
+        assert!((fits[0].coefficient - 3.0).abs() < 1e-9);
+        assert!((fits[0].predict(sizes[2]) - values[2]).abs() < 1e-6 * values[2]);
+    }
+}
+
+#[test]
+fn exponent_and_base() {
+    let sizes: Vec<usize> = vec![10, 20, 40, 80];
+    let cubes: Vec<f64> = sizes.iter().map(|n| (*n as f64).powi(3)).collect();
+    assert!((power_exponent(&sizes, &cubes) - 3.0).abs() < 1e-9);
+    let powers: Vec<f64> = sizes.iter().map(|n| 1.5f64.powi(*n as i32)).collect();
+    assert!((exponential_base(&sizes, &powers) - 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn counters_have_the_expected_growth() {
+    let settings = Settings {
+        warmups: 0,
+        repetitions: 20,
+        ..Settings::default()
+    };
+    let sizes = [64, 256, 1024, 4096, 16384];
+    let linear = estimate(&find_case("search", "linear").unwrap(), &sizes, "probes", &settings).unwrap();
+    assert_eq!(linear.best().model, Model::Linear);
+    let binary = estimate(&find_case("search", "binary").unwrap(), &sizes, "probes", &settings).unwrap();
+    assert_eq!(binary.best().model, Model::Log);
+
+    let settings = Settings {
+        warmups: 0,
+        repetitions: 1,
+        ..Settings::default()
+    };
+    let exhaustive = estimate(
+        &find_case("knapsack", "exhaustive").unwrap(),
+        &[4, 6, 8, 10, 12],
+        "calls",
+        &settings,
+    )
+    .unwrap();
+    assert_eq!(exhaustive.best().model, Model::Exponential);
+    assert_eq!(exhaustive.records().len(), Model::ALL.len());
+}
+
+#[test]
+fn quicksort_is_n_log_n_on_ordered_input() {
+    let settings = Settings {
+        warmups: 0,
+        repetitions: 1,
+        ..Settings::default()
+    };
+    let sizes = [1000, 4000, 16000, 64000];
+    for suite in ["presorted", "reversed", "organ-pipe"] {
+        let quick = estimate(&find_case(suite, "quick").unwrap(), &sizes, "comparisons", &settings).unwrap();
+        assert_eq!(quick.best().model, Model::NLogN, "{suite}");
+        let insertion = find_case(suite, "insertion").unwrap();
+        let insertion = estimate(&insertion, &[200, 400, 800, 1600], "comparisons", &settings).unwrap();
+        let expected = if suite == "presorted" { Model::Linear } else { Model::Quadratic };
+        assert_eq!(insertion.best().model, expected, "{suite}");
+    }
+}
+
+#[test]
+fn three_way_quicksort_is_linear_on_few_keys() {
+    let settings = Settings {
+        warmups: 0,
+        repetitions: 1,
+        ..Settings::default()
+    };
+    let sizes = [1000, 4000, 16000, 64000];
+    for name in ["quick-dijkstra", "quick-bm"] {
+        let estimate = estimate(&find_case("few-keys", name).unwrap(), &sizes, "comparisons", &settings).unwrap();
+        assert_eq!(estimate.best().model, Model::Linear, "{name}");
+    }
+    let lomuto = estimate(&find_case("few-keys", "quick").unwrap(), &sizes, "comparisons", &settings).unwrap();
+    assert_eq!(lomuto.best().model, Model::NLogN);
+}
+
+#[test]
+#[should_panic(expected = "no counter named swaps")]
+fn unknown_counters_are_reported() {
+    estimate(&find_case("search", "linear").unwrap(), &[1, 2, 3], "swaps", &Settings::default()).unwrap();
+}
@@ -0,0 +1,229 @@
+// Tests for the error paths that used to panic or overflow.
+
+use algo::args::Args;
+use algo::error::{parse, Error};
+use algo::metrics::Metrics;
+use algo::number_theory::{
+    factorial, fast_exp, fast_exp_mod, find_factors, find_factors_sieve, find_prime, gcd, is_probably_prime, lcm,
+    lcm_with_gcd,
+};
+use algo::prompt::Prompt;
+use algo::rng::{make_source, Prng, RandomSource};
+use algo::rsa::{generate_keys, inverse_mod, random_exponent};
+use algo::searching::binary_search;
+use algo::sorting::{
+    counting_sort, counting_sort_by_key, counting_sort_numbers, make_random_customers, Customer, MAX_COUNTS,
+};
+
+
+
+fn args(words: &[&str]) -> Args {
+    return Args::parse(words.iter().map(|word| word.to_string()).collect(), &["quiet"]);
+}
+
+#[test]
+fn factorial_reports_overflow_past_20() {
+    assert_eq!(factorial(0), Ok(1));
+    assert_eq!(factorial(20), Ok(2432902008176640000));
+    assert_eq!(factorial(21), Err(Error::Overflow(String::from("21!"))));
+    assert!(matches!(factorial(-1), Err(Error::InvalidArgument(_))));
+}
+
+#[test]
+fn fast_exp_reports_overflow() {
+    assert_eq!(fast_exp(3, 39), Ok(4052555153018976267));
+    assert_eq!(fast_exp(2, 62), Ok(1 << 62));
+    assert_eq!(fast_exp(-2, 63), Ok(i64::MIN));
+    assert_eq!(fast_exp(2, 63), Err(Error::Overflow(String::from("2^63"))));
+    assert!(matches!(fast_exp(2, -1), Err(Error::InvalidArgument(_))));
+}
+
+#[test]
+fn fast_exp_mod_handles_large_and_bad_moduli() {
+    assert_eq!(fast_exp_mod(4, 13, 497), Ok(445));
+    // (2^62)^2 would overflow an i64 without the wider products.
+    let modulus = (1i64 << 62) + 1;
+    assert_eq!(fast_exp_mod(2, 124, modulus), Ok(1));
+    assert_eq!(fast_exp_mod(-3, 3, 5), Ok(3));
+    assert_eq!(fast_exp_mod(7, 0, 1), Ok(0));
+    assert!(matches!(fast_exp_mod(2, 3, 0), Err(Error::InvalidArgument(_))));
+}
+
+#[test]
+fn gcd_and_lcm_handle_zero_and_the_minimum() {
+    assert_eq!(gcd(-12, 18), Ok(6));
+    assert_eq!(gcd(0, 0), Ok(0));
+    assert_eq!(gcd(i64::MIN, 0), Err(Error::Overflow(format!("gcd({}, 0)", i64::MIN))));
+    assert!(matches!(gcd(0, i64::MIN), Err(Error::Overflow(_))));
+    assert!(matches!(gcd(i64::MIN, i64::MIN), Err(Error::Overflow(_))));
+    assert_eq!(gcd(i64::MIN, -1), Ok(1));
+    assert_eq!(gcd(i64::MIN, 6), Ok(2));
+
+    assert_eq!(lcm(0, 0), Ok(0));
+    assert_eq!(lcm(0, 5), Ok(0));
+    assert_eq!(lcm(-4, 6), Ok(12));
+    assert_eq!(lcm(i64::MIN, 1), Err(Error::Overflow(format!("lcm({}, 1)", i64::MIN))));
+    assert!(matches!(lcm(i64::MAX, 2), Err(Error::Overflow(_))));
+    assert_eq!(lcm(i64::MIN, 0), Ok(0));
+    assert!(matches!(lcm_with_gcd(4, 6, 0), Err(Error::InvalidArgument(_))));
+    assert!(matches!(lcm_with_gcd(i64::MIN, 2, -1), Err(Error::Overflow(_))));
+}
+
+#[test]
+fn factoring_refuses_numbers_below_one() {
+    assert_eq!(find_factors(12), Ok(vec![2, 2, 3]));
+    assert_eq!(find_factors(1), Ok(vec![]));
+    assert!(matches!(find_factors(0), Err(Error::InvalidArgument(_))));
+    assert!(matches!(find_factors(-4), Err(Error::InvalidArgument(_))));
+    assert!(matches!(find_factors(i64::MIN), Err(Error::InvalidArgument(_))));
+
+    let primes = vec![2, 3, 5, 7];
+    assert_eq!(find_factors_sieve(&primes, 90), Ok(vec![2, 3, 3, 5]));
+    assert!(matches!(find_factors_sieve(&primes, 0), Err(Error::InvalidArgument(_))));
+}
+
+#[test]
+fn small_numbers_are_tested_for_primality_without_panicking() {
+    let mut prng = Prng::from_seed(1337);
+    assert!(is_probably_prime(2, 20, &mut prng));
+    assert!(is_probably_prime(3, 20, &mut prng));
+    for p in [i32::MIN, -7, 0, 1, 4] {
+        assert!(!is_probably_prime(p, 20, &mut prng), "{p}");
+    }
+}
+
+#[test]
+fn find_prime_stays_in_range_and_gives_up_without_primes() {
+    let mut prng = Prng::from_seed(1337);
+    for _ in 0..100 {
+        let p = find_prime(&mut prng, 2, 10, 20).unwrap();
+        assert!([3, 5, 7].contains(&p), "{p}");
+        // 11 is the only odd number, so p | 1 must not reach max.
+        assert_eq!(find_prime(&mut prng, 10, 12, 20), Ok(11));
+    }
+    for (min, max) in [(10, 10), (10, 5), (10, 11), (i32::MAX - 1, i32::MAX)] {
+        assert!(matches!(find_prime(&mut prng, min, max, 20), Err(Error::InvalidArgument(_))), "[{min}, {max})");
+    }
+    // 25 and 27 are the odd numbers and neither is prime.
+    assert!(matches!(find_prime(&mut prng, 24, 29, 20), Err(Error::InvalidArgument(_))));
+    assert!(matches!(find_prime(&mut prng, -10, 1, 20), Err(Error::InvalidArgument(_))));
+}
+
+#[test]
+fn inverse_mod_reports_non_invertible() {
+    assert_eq!(inverse_mod(3, 11), Ok(4));
+    assert_eq!(inverse_mod(-3, 11), Ok(7));
+    assert_eq!(inverse_mod(6, 9), Err(Error::NotInvertible { a: 6, n: 9 }));
+    assert_eq!(inverse_mod(6, 9).unwrap_err().to_string(), "6 is not invertible modulo 9");
+    assert!(matches!(inverse_mod(3, 0), Err(Error::InvalidArgument(_))));
+
+    let mut prng = Prng::from_seed(1337);
+    assert_eq!(gcd(random_exponent(&mut prng, 4).unwrap(), 4), Ok(1));
+    for λ_n in [i64::MIN, 0, 3] {
+        assert!(matches!(random_exponent(&mut prng, λ_n), Err(Error::InvalidArgument(_))));
+    }
+
+    let keys = generate_keys(&mut Prng::from_seed(1337)).unwrap();
+    assert_eq!((keys.e as i128 * keys.d as i128) % keys.lambda_n as i128, 1);
+}
+
+#[test]
+fn binary_search_handles_empty_and_small_targets() {
+    let metrics = Metrics::new();
+    assert_eq!(binary_search(&vec![], 5, &metrics), Ok(-1));
+    assert_eq!(binary_search(&vec![5], 5, &metrics), Ok(0));
+    assert_eq!(binary_search(&vec![5], 4, &metrics), Ok(-1));
+    assert_eq!(binary_search(&vec![5], 6, &metrics), Ok(-1));
+    assert_eq!(binary_search(&vec![1, 3, 5, 7], -10, &metrics), Ok(-1));
+    assert_eq!(binary_search(&vec![1, 3, 5, 7], 1, &metrics), Ok(0));
+    assert_eq!(binary_search(&vec![1, 3, 5, 7], i32::MAX, &metrics), Ok(-1));
+}
+
+#[test]
+fn counting_sort_rejects_keys_out_of_range() {
+    let customers = make_random_customers(&mut Prng::from_seed(3), 50, 10);
+    let sorted = counting_sort(&customers, 10).unwrap();
+    assert!(sorted.windows(2).all(|pair| pair[0].num_purchases <= pair[1].num_purchases));
+    assert_eq!(counting_sort(&customers, 10).map(|sorted| sorted.len()), Ok(50));
+
+    let customers = vec![
+        Customer { id: String::from("A"), num_purchases: 2 },
+        Customer { id: String::from("B"), num_purchases: 3 },
+    ];
+    assert_eq!(counting_sort(&customers, 3).err(), Some(Error::KeyOutOfRange { key: 3, max: 3 }));
+    let customers = vec![Customer { id: String::from("C"), num_purchases: -1 }];
+    assert_eq!(counting_sort(&customers, 3).err(), Some(Error::KeyOutOfRange { key: -1, max: 3 }));
+    assert_eq!(counting_sort(&vec![], 0).map(|sorted| sorted.len()), Ok(0));
+}
+
+#[test]
+fn counting_sorts_refuse_wide_key_ranges() {
+    let wide = [i64::MIN, i64::MAX];
+    assert!(matches!(counting_sort_by_key(&wide, |&key| key), Err(Error::InvalidArgument(_))));
+    let too_many = [0, MAX_COUNTS as i64];
+    assert!(matches!(counting_sort_by_key(&too_many, |&key| key), Err(Error::InvalidArgument(_))));
+    let mut numbers = [5i64, MAX_COUNTS as i64 + 5, 7];
+    assert!(matches!(counting_sort_numbers(&mut numbers, i64::MAX), Err(Error::InvalidArgument(_))));
+    assert_eq!(numbers, [5, MAX_COUNTS as i64 + 5, 7]);
+
+    // A huge max is fine when the keys themselves are close together.
+    let mut numbers = [i64::MAX - 1, i64::MAX - 3, i64::MAX - 2];
+    assert_eq!(counting_sort_numbers(&mut numbers, i64::MAX), Ok(()));
+    assert_eq!(numbers, [i64::MAX - 3, i64::MAX - 2, i64::MAX - 1]);
+}
+
+#[test]
+fn parse_errors_name_what_was_expected() {
+    assert_eq!(parse::<i64>(" 42\n", "integer"), Ok(42));
+    let error = parse::<i64>("forty", "integer").unwrap_err();
+    assert_eq!(
+        error,
+        Error::ParseError {
+            input: String::from("forty"),
+            expected: String::from("integer"),
+        }
+    );
+    assert_eq!(error.to_string(), "\"forty\" is not a valid integer");
+
+    let args = args(&["gcd", "12", "x", "--n", "ten", "--reps=3"]);
+    assert_eq!(args.parsed::<u32>("reps"), Ok(Some(3)));
+    assert_eq!(args.parsed::<u32>("seed"), Ok(None));
+    assert_eq!(args.parsed_or("seed", 7u32), Ok(7));
+    assert_eq!(
+        args.parsed::<i32>("n").unwrap_err().to_string(),
+        "\"ten\" is not a valid value for --n"
+    );
+    assert_eq!(args.positional_or_ask(1, "a", Prompt::new("A: ")), Ok(12i64));
+    assert!(matches!(args.positional_or_ask(2, "b", Prompt::<i64>::new("B: ")), Err(Error::ParseError { .. })));
+}
+
+#[test]
+fn flags_leave_the_next_word_positional() {
+    let parsed = args(&["hanoi", "--quiet", "3", "--trace", "text"]);
+    assert!(parsed.flag("quiet"));
+    assert_eq!(parsed.positional(1), Some("3"));
+    assert_eq!(parsed.value("trace"), Some("text"));
+
+    // An option that is not a flag still takes the next word.
+    let parsed = args(&["hanoi", "--show", "3"]);
+    assert!(!parsed.flag("show"));
+    assert_eq!(parsed.value("show"), Some("3"));
+    assert_eq!(parsed.positional(1), None);
+
+    // A flag is a flag wherever it goes.
+    let parsed = args(&["hanoi", "3", "--quiet"]);
+    assert!(parsed.flag("quiet"));
+    assert_eq!(parsed.positional(1), Some("3"));
+}
+
+#[test]
+fn sources_follow_seed_and_rng() {
+    let mut source = args(&["sort", "--seed", "42", "--rng", "pcg32"]).source(Some(7)).unwrap();
+    assert_eq!(source.next_u64(), make_source("pcg32", 42).unwrap().next_u64());
+    let mut source = args(&["sort", "--format", "json"]).source(Some(7)).unwrap();
+    assert_eq!(source.next_u64(), make_source("lcg", 7).unwrap().next_u64());
+
+    let error = Error::InvalidArgument(String::from("unknown generator dice"));
+    assert_eq!(args(&["sort", "--rng", "dice"]).source(None).err(), Some(error));
+    assert!(matches!(args(&["sort", "--seed", "-1"]).source(None), Err(Error::ParseError { .. })));
+}
@@ -0,0 +1,124 @@
+// Tests for the C interface: the error codes seen from Rust, and a C program
+// built against the generated header and the static library.
+
+use std::env;
+use std::ffi::CStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::ptr;
+
+use algo::ffi::*;
+
+
+
+#[test]
+fn errors_become_codes() {
+    let mut result = 0i64;
+    unsafe {
+        assert_eq!(algo_gcd(270, 192, &mut result), ALGO_OK);
+        assert_eq!(result, 6);
+        assert_eq!(algo_lcm(i64::MAX, 2, &mut result), ALGO_ERR_OVERFLOW);
+        assert_eq!(algo_gcd(i64::MIN, 0, &mut result), ALGO_ERR_OVERFLOW);
+        assert_eq!(algo_gcd(i64::MIN, 6, &mut result), ALGO_OK);
+        assert_eq!(result, 2);
+        assert_eq!(algo_inverse_mod(6, 9, &mut result), ALGO_ERR_NOT_INVERTIBLE);
+        assert_eq!(algo_fast_exp_mod(2, -1, 7, &mut result), ALGO_ERR_INVALID_ARGUMENT);
+        assert_eq!(algo_fast_exp_mod(2, 10, 7, ptr::null_mut()), ALGO_ERR_NULL_POINTER);
+
+        let mut keys = [3i32, -1, 2];
+        assert_eq!(algo_counting_sort_i32(keys.as_mut_ptr(), 3, 5), ALGO_ERR_KEY_OUT_OF_RANGE);
+        assert_eq!(keys, [3, -1, 2]);
+        assert_eq!(algo_counting_sort_i64(ptr::null_mut(), 0, 0), ALGO_OK);
+    }
+    let message = unsafe { CStr::from_ptr(algo_error_message(ALGO_ERR_NOT_INVERTIBLE)) };
+    assert_eq!(message.to_str(), Ok("the number is not invertible"));
+}
+
+#[test]
+fn factors_are_handed_over_and_back() {
+    let (mut factors, mut len) = (ptr::null_mut(), 0usize);
+    unsafe {
+        assert_eq!(algo_find_factors(2 * 3 * 3 * 1_000_003, &mut factors, &mut len), ALGO_OK);
+        assert_eq!(std::slice::from_raw_parts(factors, len), [2, 3, 3, 1_000_003]);
+        algo_free_i64(factors, len);
+
+        assert_eq!(algo_find_factors(1, &mut factors, &mut len), ALGO_OK);
+        assert_eq!(len, 0);
+        algo_free_i64(factors, len);
+        assert_eq!(algo_find_factors(-4, &mut factors, &mut len), ALGO_ERR_INVALID_ARGUMENT);
+    }
+}
+
+#[test]
+fn knapsack_selection_bitmap() {
+    // Only the last item fits; it sits in the second byte of the bitmap.
+    let values = [5, 5, 5, 5, 5, 5, 5, 5, 1];
+    let weights = [9, 9, 9, 9, 9, 9, 9, 9, 1];
+    for solver in ALGO_KNAPSACK_EXHAUSTIVE..=ALGO_KNAPSACK_DYNAMIC_PROGRAMMING {
+        let (mut selected, mut total_value) = ([0xffu8; 2], 0);
+        let code = unsafe {
+            algo_knapsack(solver, values.as_ptr(), weights.as_ptr(), 9, 1, selected.as_mut_ptr(), &mut total_value)
+        };
+        assert_eq!(code, ALGO_OK);
+        assert_eq!((selected, total_value), ([0, 1], 1), "solver {solver}");
+    }
+    let overflowing = [i32::MAX, 1];
+    let (mut selected, mut total_value) = ([0u8; 1], 0);
+    let code = unsafe {
+        algo_knapsack(0, overflowing.as_ptr(), weights.as_ptr(), 2, 1, selected.as_mut_ptr(), &mut total_value)
+    };
+    assert_eq!(code, ALGO_ERR_OVERFLOW);
+}
+
+// The C compiler: $CC, or else cc on the PATH.
+fn c_compiler() -> String {
+    return env::var("CC").ok().filter(|cc| !cc.is_empty()).unwrap_or_else(|| String::from("cc"));
+}
+
+// Where cargo put the static library: two levels above this test binary.
+fn target_dir() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    return exe.parent().unwrap().parent().unwrap().to_path_buf();
+}
+
+// The header build.rs generated from src/ffi.rs.
+const GENERATED_HEADER: &str = include_str!(concat!(env!("OUT_DIR"), "/algo.h"));
+
+#[test]
+fn committed_header_is_up_to_date() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let header = fs::read_to_string(root.join("include/algo.h")).unwrap();
+    assert!(
+        header == GENERATED_HEADER,
+        "include/algo.h is out of date; copy {}/algo.h over it",
+        env!("OUT_DIR")
+    );
+}
+
+#[test]
+fn c_program_passes() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let header = fs::read_to_string(root.join("include/algo.h")).unwrap();
+    for name in ["algo_quicksort_i64", "algo_find_factors", "algo_knapsack", "ALGO_ERR_PANIC"] {
+        assert!(header.contains(name), "include/algo.h is missing {name}");
+    }
+
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("algo_test");
+    let cc = c_compiler();
+    let output = Command::new(&cc)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(root.join("include"))
+        .arg(root.join("tests/c/algo_test.c"))
+        .arg(target_dir().join("libalgo.a"))
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&program)
+        .output()
+        .unwrap_or_else(|error| panic!("cannot run the C compiler {cc:?} ({error}); set CC to one"));
+    assert!(output.status.success(), "{cc} failed:\n{}", String::from_utf8_lossy(&output.stderr));
+
+    let output = Command::new(&program).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.ends_with("checks passed\n"));
+}
@@ -0,0 +1,175 @@
+// Differential tests for the knapsack solvers: on many small random
+// instances they must all find the optimal value, and each must return a
+// selection that fits and adds up to the value it reports.
+
+use algo::check::{assert_property, check, shrink_i64, shrink_vec};
+use algo::knapsack::{
+    branch_and_bound, dynamic_programming, exhaustive_search, rods_technique, rods_technique_sorted,
+    sum_values, sum_weights, Item, Solver,
+};
+use algo::metrics::Metrics;
+use algo::rng::{Sampling, SplitMix64};
+
+const SEED: u64 = 1337;
+const NUM_CASES: usize = 500;
+
+
+
+// An instance as plain numbers, so it can be cloned, shrunk and printed.
+#[derive(Clone, Debug)]
+struct Instance {
+    // (value, weight) pairs.
+    items: Vec<(i32, i32)>,
+    allowed_weight: i32,
+}
+
+fn make_items(instance: &Instance) -> Vec<Item> {
+    let mut items: Vec<Item> = vec![];
+    for i in 0..instance.items.len() {
+        let (value, weight) = instance.items[i];
+        items.push(Item {
+            id: i as i32,
+            value,
+            weight,
+            is_selected: false,
+            blocked_by: -1,
+            block_list: vec![],
+        });
+    }
+    return items;
+}
+
+// Up to 10 items with small values and weights, zeros and duplicates
+// included, since those are where dominance and pruning get tricky.
+fn generate(prng: &mut SplitMix64) -> Instance {
+    let num_items = prng.range_i32(0, 11) as usize;
+    let mut items: Vec<(i32, i32)> = vec![];
+    for _ in 0..num_items {
+        items.push((prng.range_i32(0, 11), prng.range_i32(0, 11)));
+    }
+    let total_weight: i32 = items.iter().map(|item| item.1).sum();
+    let allowed_weight = prng.range_i32(0, total_weight + 2);
+    return Instance { items, allowed_weight };
+}
+
+fn shrink(instance: &Instance) -> Vec<Instance> {
+    let mut candidates: Vec<Instance> = vec![];
+    let shrink_item = |&(value, weight): &(i32, i32)| {
+        let mut smaller: Vec<(i32, i32)> = vec![];
+        for value in shrink_i64(value as i64, 0) {
+            smaller.push((value as i32, weight));
+        }
+        for weight in shrink_i64(weight as i64, 0) {
+            smaller.push((value, weight as i32));
+        }
+        return smaller;
+    };
+    for items in shrink_vec(&instance.items, shrink_item) {
+        candidates.push(Instance { items, ..instance.clone() });
+    }
+    for allowed_weight in shrink_i64(instance.allowed_weight as i64, 0) {
+        candidates.push(Instance { allowed_weight: allowed_weight as i32, ..instance.clone() });
+    }
+    return candidates;
+}
+
+// The best value, by trying every subset.
+fn optimal_value(instance: &Instance) -> i32 {
+    let mut best = 0;
+    for subset in 0..(1u32 << instance.items.len()) {
+        let (mut value, mut weight) = (0, 0);
+        for i in 0..instance.items.len() {
+            if subset & (1 << i) != 0 {
+                value += instance.items[i].0;
+                weight += instance.items[i].1;
+            }
+        }
+        if weight <= instance.allowed_weight && value > best {
+            best = value;
+        }
+    }
+    return best;
+}
+
+fn solvers() -> Vec<(&'static str, Box<Solver>)> {
+    return vec![
+        ("exhaustive", Box::new(exhaustive_search)),
+        ("bnb", Box::new(branch_and_bound)),
+        ("rods", Box::new(rods_technique)),
+        ("rods-sorted", Box::new(rods_technique_sorted)),
+        ("dp", Box::new(dynamic_programming)),
+    ];
+}
+
+// Check one solver's answer: the value is optimal, the selection fits, its
+// values add up to the reported value, and every selected item is one of
+// the instance's items, used at most as often as it appears.
+fn check_solver(name: &str, solver: &Solver, instance: &Instance) -> Result<(), String> {
+    let mut items = make_items(instance);
+    let (solution, value) = solver(&mut items, instance.allowed_weight, &Metrics::new()).map_err(|error| format!("{name} failed: {error}"))?;
+    let optimal = optimal_value(instance);
+    if value != optimal {
+        return Err(format!("{name} found value {value}, the optimum is {optimal}"));
+    }
+    let weight = sum_weights(&solution, false).unwrap();
+    if weight > instance.allowed_weight {
+        return Err(format!("{name}'s selection weighs {weight} > {}", instance.allowed_weight));
+    }
+    let selected_value = sum_values(&solution, false).unwrap();
+    if selected_value != value {
+        return Err(format!("{name}'s selection is worth {selected_value}, not {value}"));
+    }
+    let mut unused = instance.items.clone();
+    for item in solution.iter().filter(|item| item.is_selected) {
+        match unused.iter().position(|&pair| pair == (item.value, item.weight)) {
+            Some(index) => {
+                unused.swap_remove(index);
+            }
+            None => return Err(format!("{name} selected ({}, {}), which is not available", item.value, item.weight)),
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn solvers_agree_with_brute_force() {
+    for (name, solver) in solvers() {
+        assert_property(SEED, NUM_CASES, generate, shrink, |instance| check_solver(name, &*solver, instance));
+    }
+}
+
+#[test]
+fn solvers_handle_edge_cases() {
+    let instances = [
+        Instance { items: vec![], allowed_weight: 10 },
+        Instance { items: vec![(5, 3)], allowed_weight: 0 },
+        Instance { items: vec![(0, 0), (0, 0)], allowed_weight: 0 },
+        Instance { items: vec![(4, 0), (4, 2), (4, 2)], allowed_weight: 2 },
+        Instance { items: vec![(3, 4), (3, 4), (3, 4)], allowed_weight: 8 },
+    ];
+    for (name, solver) in solvers() {
+        for instance in instances.iter() {
+            if let Err(message) = check_solver(name, &*solver, instance) {
+                panic!("{message} on {instance:?}");
+            }
+        }
+    }
+}
+
+// The shrinker itself: a property that fails whenever some item is worth
+// more than 8 should shrink to a single item worth 9 that weighs nothing.
+#[test]
+fn failures_shrink_to_a_minimal_instance() {
+    let failure = check(SEED, NUM_CASES, generate, shrink, |instance| {
+        if instance.items.iter().any(|item| item.0 > 8) {
+            return Err(String::from("an item is worth more than 8"));
+        }
+        return Ok(());
+    })
+    .unwrap();
+    assert_eq!(failure.shrunk.items, [(9, 0)]);
+    assert_eq!(failure.shrunk.allowed_weight, 0);
+    assert!(failure.num_shrinks > 0);
+    assert_eq!(generate(&mut SplitMix64::from_seed(failure.seed)).items, failure.original.items);
+    assert!(failure.to_string().starts_with(&format!("property failed on case {}", failure.case)));
+}
@@ -0,0 +1,178 @@
+// Tests for knapsack instances with other kinds of number: the solvers
+// agree on values too big for an i32, fixed-point numbers read and print
+// as decimals, and totals that overflow are errors rather than wrong
+// answers.
+
+use std::env;
+use std::fs;
+use std::process;
+
+use algo::error::Error;
+use algo::knapsack::{
+    branch_and_bound, dynamic_programming, exhaustive_search, read_instance, rods_technique, rods_technique_sorted,
+    solution_value, sum_values, Fixed, Item, Number, Solver,
+};
+use algo::metrics::Metrics;
+
+
+
+fn make_items<V: Number, W: Number>(pairs: &[(V, W)]) -> Vec<Item<V, W>> {
+    let mut items: Vec<Item<V, W>> = vec![];
+    for i in 0..pairs.len() {
+        items.push(Item {
+            id: i as i32,
+            value: pairs[i].0,
+            weight: pairs[i].1,
+            is_selected: false,
+            blocked_by: -1,
+            block_list: vec![],
+        });
+    }
+    return items;
+}
+
+fn solvers<V: Number, W: Number>() -> Vec<(&'static str, Box<Solver<V, W>>)> {
+    return vec![
+        ("exhaustive", Box::new(exhaustive_search)),
+        ("bnb", Box::new(branch_and_bound)),
+        ("rods", Box::new(rods_technique)),
+        ("rods-sorted", Box::new(rods_technique_sorted)),
+        ("dp", Box::new(dynamic_programming)),
+    ];
+}
+
+// Run every solver and check they all find the expected value with a
+// selection worth that much.
+fn assert_all_find<V: Number, W: Number>(pairs: &[(V, W)], allowed_weight: W, expected: V) {
+    for (name, solver) in solvers::<V, W>() {
+        let mut items = make_items(pairs);
+        let (solution, value) = solver(&mut items, allowed_weight, &Metrics::new()).unwrap();
+        assert_eq!(value, expected, "{name}");
+        assert_eq!(solution_value(&solution, allowed_weight).unwrap(), Some(expected), "{name}");
+    }
+}
+
+fn temp_path(name: &str) -> String {
+    let path = env::temp_dir().join(format!("algo-{}-{name}", process::id()));
+    return path.to_string_lossy().into_owned();
+}
+
+
+
+#[test]
+fn big_values_agree_across_solvers() {
+    // Prices in cents whose total is far beyond an i32.
+    let pairs: Vec<(i64, i64)> = vec![(3_000_000_000, 5), (2_500_000_000, 4), (1_000_000_000, 3), (40_000_000_000, 9)];
+    assert_all_find(&pairs, 12, 41_000_000_000);
+
+    let big = u64::MAX / 4;
+    let pairs: Vec<(u64, u32)> = vec![(big, 3), (big, 3), (big, 3), (1, 1)];
+    assert_all_find(&pairs, 7, 2 * big + 1);
+
+    let huge = u128::MAX / 3;
+    let pairs: Vec<(u128, u64)> = vec![(huge, 10), (huge, 20), (7, 1)];
+    assert_all_find(&pairs, 30, 2 * huge);
+}
+
+#[test]
+fn fixed_point_instances_solve_in_units() {
+    let price = |text: &str| text.parse::<Fixed<2>>().unwrap();
+    let grams = |text: &str| text.parse::<Fixed<1>>().unwrap();
+    let pairs = vec![
+        (price("19.99"), grams("2.5")),
+        (price("5.25"), grams("1.0")),
+        (price("12.50"), grams("1.5")),
+        (price("0.99"), grams("0.1")),
+    ];
+    assert_all_find(&pairs, grams("4.1"), price("33.48"));
+}
+
+#[test]
+fn fixed_point_reads_and_prints_as_decimals() {
+    for text in ["12.34", "0.05", "-3.10", "0.00", "100.00"] {
+        assert_eq!(text.parse::<Fixed<2>>().unwrap().to_string(), text);
+    }
+    assert_eq!("12".parse::<Fixed<2>>().unwrap(), Fixed(1200));
+    assert_eq!("1.5".parse::<Fixed<3>>().unwrap(), Fixed(1500));
+    assert_eq!("-0.5".parse::<Fixed<2>>().unwrap().units(), -50);
+    assert_eq!(Fixed::<2>(1234).to_f64(), 12.34);
+    assert_eq!(Fixed::<0>(7).to_string(), "7");
+
+    // Places that would be lost are an error, not rounded away.
+    for text in ["1.234", "", ".5", "1.2.3", "abc", "1e3", "+1", "99999999999999999999"] {
+        let parsed = text.parse::<Fixed<2>>();
+        assert!(matches!(parsed, Err(Error::ParseError { .. })), "{text:?} parsed as {parsed:?}");
+    }
+}
+
+#[test]
+fn instance_files_hold_any_number_type() {
+    let path = temp_path("fixed.txt");
+    fs::write(&path, "# allowed weight\n2.5\n1.25 1.5\n2.00 1.0\n0.10 2.0\n").unwrap();
+    let (items, allowed_weight) = read_instance::<Fixed<2>, Fixed<2>>(&path).unwrap();
+    assert_eq!(allowed_weight, Fixed(250));
+    assert_eq!(items.iter().map(|item| (item.value, item.weight)).collect::<Vec<_>>(), [
+        (Fixed(125), Fixed(150)),
+        (Fixed(200), Fixed(100)),
+        (Fixed(10), Fixed(200)),
+    ]);
+    // Integers read the same file only if it has no decimals.
+    assert!(read_instance::<i64, i64>(&path).is_err());
+
+    fs::write(&path, "18446744073709551615\n18446744073709551615 1\n").unwrap();
+    assert!(read_instance::<u64, u64>(&path).is_ok());
+    assert!(read_instance::<i64, i64>(&path).is_err());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn overflowing_totals_are_errors() {
+    let overflow = Error::Overflow(String::from("the sum of the values"));
+
+    // Values that fit one by one but not added up.
+    let pairs = vec![(i32::MAX, 1), (1, 1)];
+    assert_eq!(sum_values(&make_items(&pairs), true), Err(overflow.clone()));
+    for (name, solver) in solvers::<i32, i32>() {
+        let result = solver(&mut make_items(&pairs), 1, &Metrics::new());
+        assert_eq!(result.err(), Some(overflow.clone()), "{name}");
+    }
+
+    // The same instance in a wider type is fine.
+    let pairs: Vec<(i64, i32)> = vec![(i32::MAX as i64, 1), (1, 1)];
+    assert_all_find(&pairs, 2, i32::MAX as i64 + 1);
+
+    // Weights that overflow only make a selection too heavy.
+    let mut items = make_items(&[(1, u32::MAX), (1, 1)]);
+    for item in items.iter_mut() {
+        item.is_selected = true;
+    }
+    assert_eq!(solution_value(&items, u32::MAX), Ok(None));
+    // The table would have four billion columns, so leave out the dp solver.
+    for (name, solver) in solvers::<i32, u32>().into_iter().filter(|(name, _)| *name != "dp") {
+        let mut items = make_items(&[(1, u32::MAX), (2, 1)]);
+        assert_eq!(solver(&mut items, u32::MAX, &Metrics::new()).unwrap().1, 2, "{name}");
+    }
+}
+
+#[test]
+fn dynamic_programming_refuses_tables_too_big_to_allocate() {
+    let mut items = make_items(&[(5u64, 2u64), (9, 3)]);
+    let result = dynamic_programming(&mut items, u64::MAX, &Metrics::new());
+    assert!(matches!(result, Err(Error::Overflow(_))), "{:?}", result.map(|(_, value)| value));
+
+    // The other solvers do not need a table.
+    let mut items = make_items(&[(5u64, 2u64), (9, 3)]);
+    assert_eq!(branch_and_bound(&mut items, u64::MAX, &Metrics::new()).unwrap().1, 14);
+}
+
+#[test]
+fn negative_numbers_are_rejected() {
+    for (name, solver) in solvers::<i64, i64>() {
+        let negative_value = solver(&mut make_items(&[(-1, 1), (2, 1)]), 2, &Metrics::new());
+        assert!(matches!(negative_value, Err(Error::InvalidArgument(_))), "{name}");
+        let negative_weight = solver(&mut make_items(&[(1, -1), (2, 1)]), 2, &Metrics::new());
+        assert!(matches!(negative_weight, Err(Error::InvalidArgument(_))), "{name}");
+        let negative_allowed = solver(&mut make_items(&[(1, 1)]), -1, &Metrics::new());
+        assert!(matches!(negative_allowed, Err(Error::InvalidArgument(_))), "{name}");
+    }
+}
@@ -0,0 +1,136 @@
+// Tests for the Metrics context and the counters the algorithms report.
+
+use std::thread;
+use std::time::Duration;
+
+use algo::backtracking::{hanoi, knight_tour, nqueens};
+use algo::knapsack::{branch_and_bound, exhaustive_search, make_items, sum_weights};
+use algo::metrics::{Counter, Metrics};
+use algo::number_theory::gcd_traced;
+use algo::output::{Record, Value};
+use algo::rng::Prng;
+use algo::searching::{binary_search, linear_search};
+use algo::sorting::{bubble_sort, insertion_sort, quicksort};
+
+
+
+#[test]
+fn counters_are_shared_across_threads() {
+    let metrics = Metrics::new();
+    thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for _ in 0..1000 {
+                    metrics.count(Counter::Calls);
+                }
+                metrics.add(Counter::Swaps, 2);
+            });
+        }
+    });
+    assert_eq!(metrics.get(Counter::Calls), 8000);
+    assert_eq!(metrics.get(Counter::Swaps), 16);
+    assert_eq!(metrics.counters(), vec![("calls", 8000), ("swaps", 16)]);
+
+    metrics.reset();
+    assert!(metrics.counters().is_empty());
+}
+
+#[test]
+fn timers_accumulate() {
+    let metrics = Metrics::new();
+    let value = metrics.time("work", || {
+        thread::sleep(Duration::from_millis(5));
+        return 7;
+    });
+    assert_eq!(value, 7);
+    metrics.add_time("work", Duration::from_millis(10));
+    assert!(metrics.elapsed("work") >= Duration::from_millis(15));
+    assert_eq!(metrics.elapsed("idle"), Duration::ZERO);
+
+    metrics.count(Counter::Probes);
+    assert!(metrics.report().starts_with("probes=1 work="));
+
+    let mut record = Record::new("run");
+    metrics.add_to(&mut record);
+    assert_eq!(record.fields.len(), Counter::ALL.len() + 1);
+    assert_eq!(record.get("calls"), Some(&Value::Int(0)));
+}
+
+#[test]
+fn sorts_count_comparisons_and_swaps() {
+    let metrics = Metrics::new();
+    let mut vec = vec![3, 1, 2];
+    bubble_sort(&mut vec, &metrics);
+    assert_eq!(vec, vec![1, 2, 3]);
+    assert_eq!(metrics.get(Counter::Swaps), 2); // The number of inversions.
+    assert_eq!(metrics.get(Counter::Comparisons), 3);
+
+    let metrics = Metrics::new();
+    let mut vec = vec![3, 1, 2];
+    insertion_sort(&mut vec, &metrics);
+    assert_eq!(vec, vec![1, 2, 3]);
+    assert_eq!(metrics.get(Counter::Swaps), 2);
+    assert_eq!(metrics.get(Counter::Comparisons), 3);
+
+    // The median of three splits sorted input evenly, where the last
+    // element as the pivot took all 99·100/2 comparisons.
+    let metrics = Metrics::new();
+    let mut vec: Vec<i32> = (0..100).collect();
+    quicksort(&mut vec, &metrics);
+    assert!(metrics.get(Counter::Comparisons) < 100 * 7);
+}
+
+#[test]
+fn searches_count_probes() {
+    let vec: Vec<i32> = (0..100).collect();
+    let metrics = Metrics::new();
+    assert_eq!(linear_search(&vec, 41, &metrics), 41);
+    assert_eq!(metrics.get(Counter::Probes), 42);
+
+    let metrics = Metrics::new();
+    assert_eq!(binary_search(&vec, 49, &metrics), Ok(49));
+    assert_eq!(metrics.get(Counter::Probes), 1);
+    assert_eq!(binary_search(&vec, 1000, &metrics), Ok(-1));
+    assert!(metrics.get(Counter::Probes) <= 9);
+}
+
+#[test]
+fn recursive_algorithms_count_calls() {
+    let metrics = Metrics::new();
+    let mut posts = hanoi::make_posts(6);
+    hanoi::move_disks(&mut posts, 6, 0, 1, 2, false, &metrics);
+    assert_eq!(metrics.get(Counter::Moves), 63);
+
+    let metrics = Metrics::new();
+    assert_eq!(gcd_traced(270, 192, &metrics), Ok(6));
+    assert_eq!(metrics.get(Counter::Calls), 5);
+
+    let metrics = Metrics::new();
+    let mut board = knight_tour::make_board(5, 5);
+    board[0][0] = 0;
+    assert!(knight_tour::find_tour(&mut board, &knight_tour::OFFSETS, 0, 0, 1, false, &metrics));
+    assert!(metrics.get(Counter::Calls) >= 25);
+
+    let metrics = Metrics::new();
+    let mut board = nqueens::make_board(6);
+    assert!(nqueens::place_queens_4(&mut board, 0, &metrics));
+    assert!(metrics.get(Counter::Prunes) > 0);
+}
+
+#[test]
+fn branch_and_bound_prunes_what_exhaustive_search_visits() {
+    let mut prng = Prng::from_seed(1337);
+    let mut items = make_items(&mut prng, 12, 1, 10, 4, 10);
+    let allowed_weight = sum_weights(&items, true).unwrap() / 2;
+
+    let exhaustive = Metrics::new();
+    let (_, best) = exhaustive_search(&mut items, allowed_weight, &exhaustive).unwrap();
+    assert_eq!(exhaustive.get(Counter::Calls), (1 << 13) - 1);
+    assert_eq!(exhaustive.get(Counter::Prunes), 0);
+
+    let bnb = Metrics::new();
+    let (_, value) = branch_and_bound(&mut items, allowed_weight, &bnb).unwrap();
+    assert_eq!(value, best);
+    assert!(bnb.get(Counter::Prunes) > 0);
+    assert!(bnb.get(Counter::Calls) < exhaustive.get(Counter::Calls));
+}
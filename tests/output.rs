@@ -0,0 +1,91 @@
+// Rendering tests for the text, JSON and CSV output layer.
+
+use std::time::Duration;
+
+use algo::knapsack::{dynamic_programming, make_items, solution_record, time_algorithm};
+use algo::metrics::Metrics;
+use algo::output::{
+    render_csv, render_csv_header, render_json, render_text, Format, Record, Value, Writer,
+    FORMAT_NAMES,
+};
+use algo::rng::Prng;
+
+
+
+fn sample() -> Record {
+    return Record::new("factor")
+        .with("num", 360)
+        .with("factors", vec![2i64, 2, 2, 3, 3, 5])
+        .with("ok", true)
+        .with("elapsed", Duration::from_millis(1500));
+}
+
+#[test]
+fn json_is_one_object_per_record() {
+    assert_eq!(
+        render_json(&sample()),
+        r#"{"kind":"factor","num":360,"factors":[2,2,2,3,3,5],"ok":true,"elapsed":1.5}"#
+    );
+    let record = Record::new("x")
+        .with("name", "a \"quoted\"\\\n\tline")
+        .with("nan", f64::NAN);
+    assert_eq!(
+        render_json(&record),
+        r#"{"kind":"x","name":"a \"quoted\"\\\n\tline","nan":null}"#
+    );
+}
+
+#[test]
+fn csv_quotes_only_when_needed() {
+    assert_eq!(render_csv_header(&sample()), "kind,num,factors,ok,elapsed");
+    assert_eq!(render_csv(&sample()), "factor,360,2 2 2 3 3 5,true,1.5");
+    let record = Record::new("x").with("name", "a, \"b\"");
+    assert_eq!(render_csv(&record), r#"x,"a, ""b""""#);
+}
+
+#[test]
+fn boards_render_row_by_row() {
+    let board = vec![vec![0, 11], vec![2, 3]];
+    let record = Record::new("knight").with("board", &board);
+    assert_eq!(render_text(&record), "[knight]\nboard:\n   0 11\n   2  3\n");
+    assert_eq!(render_csv(&record), "knight,0 11/2 3");
+    assert_eq!(render_json(&record), r#"{"kind":"knight","board":[[0,11],[2,3]]}"#);
+}
+
+#[test]
+fn csv_writer_repeats_the_header_when_columns_change() {
+    let mut writer = Writer::new(Format::Csv, Vec::new());
+    writer.write(&Record::new("a").with("x", 1)).unwrap();
+    writer.write(&Record::new("a").with("x", 2)).unwrap();
+    writer.write(&Record::new("b").with("y", 3)).unwrap();
+    let text = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(text, "kind,x\na,1\na,2\nkind,y\nb,3\n");
+
+    for name in FORMAT_NAMES {
+        assert!(Format::from_name(name).is_some());
+    }
+    assert!(Format::from_name("xml").is_none());
+}
+
+#[test]
+fn knapsack_records_list_the_selected_ids() {
+    let mut prng = Prng::from_seed(1337);
+    let mut items = make_items(&mut prng, 8, 1, 10, 4, 10);
+    let metrics = Metrics::new();
+    let (solution, value) = time_algorithm(&dynamic_programming, &mut items, 40, &metrics).unwrap();
+    let record = solution_record("dp", &solution, value, &metrics).unwrap();
+    assert_eq!(record.get("calls"), Some(&Value::Int(1)));
+    assert!(matches!(record.get("elapsed"), Some(Value::Float(_))));
+    assert_eq!(record.get("value"), Some(&Value::Int(value as i64)));
+    let Some(Value::List(ids)) = record.get("selected") else {
+        panic!("no selected list");
+    };
+    let total: i64 = ids
+        .iter()
+        .map(|id| match id {
+            Value::Int(id) => items[*id as usize].value as i64,
+            _ => panic!("bad id"),
+        })
+        .sum();
+    assert_eq!(total, value as i64);
+}
@@ -0,0 +1,163 @@
+// Tests for progress reports: what the routines report, how the interval
+// thins the reports out, and how the progress bar draws them.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use algo::backtracking::{knight_tour, nqueens};
+use algo::budget::Budget;
+use algo::knapsack::{branch_and_bound, dynamic_programming, make_items, sum_weights};
+use algo::metrics::{Counter, Metrics};
+use algo::number_theory::{find_factors_with_progress, sieve_of_eratosthenes, sieve_of_eratosthenes_with_progress};
+use algo::progress::{render, Observer, Progress, ProgressBar, Report};
+use algo::rng::SplitMix64;
+use algo::sorting::{bubble_sort, quicksort};
+
+
+
+// Return a Metrics that keeps every report, and the reports it keeps.
+fn recording(interval: Duration) -> (Metrics, Arc<Mutex<Vec<Report>>>) {
+    let reports = Arc::new(Mutex::new(vec![]));
+    let sink = reports.clone();
+    let progress = Progress::new(move |report: &Report| sink.lock().unwrap().push(report.clone()));
+    return (Metrics::new().with_progress(progress.with_interval(interval)), reports);
+}
+
+// Check the fractions only grow and the last report is done.
+fn assert_well_formed(reports: &Vec<Report>, task: &str) {
+    assert!(reports.len() >= 2, "{task} sent {} reports", reports.len());
+    for i in 0..reports.len() {
+        assert_eq!(reports[i].task, task);
+        assert_eq!(reports[i].done, i == reports.len() - 1);
+        if i > 0 {
+            assert!(reports[i].nodes >= reports[i - 1].nodes);
+            assert!(reports[i].fraction >= reports[i - 1].fraction);
+        }
+    }
+}
+
+
+
+#[test]
+fn sieve_reports_the_fraction_done() {
+    let (metrics, reports) = recording(Duration::ZERO);
+    let sieve = sieve_of_eratosthenes_with_progress(1_000_000, &metrics);
+    metrics.finish_progress();
+    assert_eq!(sieve, sieve_of_eratosthenes(1_000_000));
+
+    let reports = reports.lock().unwrap();
+    assert_well_formed(&reports, "sieve");
+    // One report per odd number below the square root, and the last.
+    assert_eq!(reports.len(), 499 + 1);
+    assert!(reports[reports.len() - 2].fraction.unwrap() > 0.8);
+    assert_eq!(reports[reports.len() - 1].fraction, Some(1.0));
+}
+
+#[test]
+fn factoring_reports_now_and_then() {
+    let (metrics, reports) = recording(Duration::ZERO);
+    assert_eq!(find_factors_with_progress(1_000_003 * 1_000_033, &metrics).unwrap(), [1_000_003, 1_000_033]);
+    metrics.finish_progress();
+    let reports = reports.lock().unwrap();
+    assert_well_formed(&reports, "factor");
+    assert!(reports.len() > 10 && reports.len() < 20);
+}
+
+#[test]
+fn searches_report_every_node_and_their_depth() {
+    let (metrics, reports) = recording(Duration::ZERO);
+    assert!(nqueens::place_queens_4(&mut nqueens::make_board(8), 0, &metrics));
+    metrics.finish_progress();
+    let reports = reports.lock().unwrap();
+    assert_well_formed(&reports, "queens");
+    assert_eq!(reports.len() as u64, metrics.get(Counter::Calls) + 1);
+    assert!(reports.iter().all(|report| report.depth <= 8 && report.fraction.is_none()));
+    assert_eq!(reports.iter().map(|report| report.depth).max(), Some(8));
+
+    let (metrics, reports) = recording(Duration::ZERO);
+    let mut items = make_items(&mut SplitMix64::from_seed(7), 12, 1, 10, 4, 10);
+    let allowed_weight = sum_weights(&items, true).unwrap() / 2;
+    branch_and_bound(&mut items, allowed_weight, &metrics).unwrap();
+    dynamic_programming(&mut items, allowed_weight, &metrics).unwrap();
+    metrics.finish_progress();
+    let reports = reports.lock().unwrap();
+    assert!(reports.iter().all(|report| report.task == "knapsack" && report.depth <= 12));
+    // The dynamic programming table is filled a row at a time.
+    assert_eq!(reports.iter().filter(|report| report.fraction.is_some()).count(), 11 + 1);
+}
+
+#[test]
+fn sorts_report_their_progress() {
+    let (metrics, reports) = recording(Duration::ZERO);
+    let mut vec: Vec<i32> = (0..100).rev().collect();
+    bubble_sort(&mut vec, &metrics);
+    metrics.finish_progress();
+    assert_well_formed(&reports.lock().unwrap(), "sort");
+
+    let (metrics, reports) = recording(Duration::ZERO);
+    let mut vec: Vec<i32> = (0..10_000).collect();
+    quicksort(&mut vec, &metrics);
+    metrics.finish_progress();
+    // Quicksort halves sorted input, so it goes about log₂(n / 16) deep.
+    let max_depth = reports.lock().unwrap().iter().map(|report| report.depth).max().unwrap();
+    assert!((5..=13).contains(&max_depth), "quicksort went {max_depth} deep");
+}
+
+#[test]
+fn the_interval_limits_reports() {
+    let (metrics, reports) = recording(Duration::from_secs(3600));
+    let mut board = knight_tour::make_board(5, 5);
+    board[0][0] = 0;
+    assert!(knight_tour::find_tour(&mut board, &knight_tour::OFFSETS, 0, 0, 1, false, &metrics));
+    metrics.finish_progress();
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].nodes, 1);
+    assert_eq!(reports[1].nodes, metrics.get(Counter::Calls));
+}
+
+#[test]
+fn stopped_searches_report_where_they_stopped() {
+    let (metrics, reports) = recording(Duration::ZERO);
+    let metrics = metrics.with_budget(Budget::unlimited().with_max_nodes(100));
+    assert!(!nqueens::place_queens_4(&mut nqueens::make_board(20), 0, &metrics));
+    metrics.finish_progress();
+    let reports = reports.lock().unwrap();
+    assert_well_formed(&reports, "queens");
+    assert!(reports[reports.len() - 1].nodes > 100);
+}
+
+#[test]
+fn progress_bar_draws_in_place() {
+    let mut report = Report {
+        task: "sieve",
+        nodes: 0,
+        depth: 0,
+        fraction: Some(0.5),
+        elapsed: Duration::from_millis(1250),
+        done: false,
+    };
+    assert_eq!(render(&report), "sieve      [###############...............]  50% 1.2s");
+    report.fraction = Some(1.5);
+    assert!(render(&report).contains("[##############################] 100%"));
+
+    let search = Report {
+        task: "queens",
+        nodes: 1234,
+        depth: 7,
+        fraction: None,
+        elapsed: Duration::from_secs(2),
+        done: true,
+    };
+    assert_eq!(render(&search), "queens     1234 nodes, depth 7, 2.0s");
+
+    // Each line goes back to the start of the line, a shorter one is padded
+    // to cover the last, and the finishing one ends the line.
+    let mut bar = ProgressBar::new(vec![]);
+    let long = Report { nodes: 123456, depth: 12, done: false, ..search.clone() };
+    bar.observe(&long);
+    bar.observe(&search);
+    let text = String::from_utf8(bar.into_inner()).unwrap();
+    let lines: Vec<&str> = text.split('\r').collect();
+    assert_eq!(lines, ["", "queens     123456 nodes, depth 12, 2.0s", "queens     1234 nodes, depth 7, 2.0s   \n"]);
+}
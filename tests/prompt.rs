@@ -0,0 +1,156 @@
+// Tests for the typed prompts and scripted sessions.
+
+use std::cell::RefCell;
+use std::env;
+use std::fs;
+use std::io::{Cursor, Write};
+use std::process;
+use std::rc::Rc;
+
+use algo::args::Args;
+use algo::error::Error;
+use algo::prompt::{Prompt, Prompter};
+
+
+
+// Collects a prompter's output so the test can read it afterwards.
+#[derive(Clone, Default)]
+struct Screen(Rc<RefCell<Vec<u8>>>);
+
+impl Write for Screen {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        return Ok(buf.len());
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        return Ok(());
+    }
+}
+
+impl Screen {
+    fn text(&self) -> String {
+        return String::from_utf8(self.0.borrow().clone()).unwrap();
+    }
+}
+
+
+
+fn scripted(script: &str) -> (Prompter, Screen) {
+    let screen = Screen::default();
+    let prompter = Prompter::from_reader(Cursor::new(script.to_string())).with_output(Box::new(screen.clone()));
+    return (prompter, screen);
+}
+
+fn temp_path(name: &str) -> String {
+    let path = env::temp_dir().join(format!("algo-{}-{name}", process::id()));
+    return path.to_string_lossy().into_owned();
+}
+
+#[test]
+fn bad_answers_are_asked_again() {
+    let (mut prompter, screen) = scripted("ten\n-5\n\n10\n");
+    assert_eq!(prompter.ask(&Prompt::new("# Items: ").min(1)), Ok(10));
+    assert_eq!(
+        screen.text(),
+        "# Items: ten\n\
+         \"ten\" is not a valid number. Please try again.\n\
+         # Items: -5\n\
+         -5 is out of range, expected at least 1. Please try again.\n\
+         # Items: \n\
+         Please enter a value.\n\
+         # Items: 10\n"
+    );
+    assert_eq!(prompter.history, ["10"]);
+}
+
+#[test]
+fn ranges_are_described() {
+    let prompt = Prompt::new("N: ").range(0, 92);
+    assert_eq!(prompt.check(" 7 "), Ok(7i64));
+    assert_eq!(prompt.check("93").unwrap_err().to_string(), "93 is out of range, expected between 0 and 92");
+    let prompt = Prompt::new("N: ").max(9);
+    assert_eq!(prompt.check("10").unwrap_err().to_string(), "10 is out of range, expected at most 9");
+    assert_eq!(prompt.check("-10"), Ok(-10i32));
+}
+
+#[test]
+fn blank_answers_take_the_default() {
+    let (mut prompter, screen) = scripted("# a comment\n\n");
+    let prompt = Prompt::new("# Items: ").min(1).with_default(20);
+    assert_eq!(prompt.display(), "# Items [20]: ");
+    assert_eq!(prompter.ask(&prompt), Ok(20));
+    assert_eq!(screen.text(), "# Items [20]: \n");
+}
+
+#[test]
+fn quit_and_end_of_input_end_the_session() {
+    let (mut prompter, _) = scripted("5\nq\n");
+    assert_eq!(prompter.ask(&Prompt::new("N: ")), Ok(5));
+    assert_eq!(prompter.ask(&Prompt::<i32>::new("N: ")), Err(Error::EndOfInput));
+
+    let (mut prompter, _) = scripted("quit\n");
+    assert_eq!(prompter.ask(&Prompt::<i32>::new("N: ")), Err(Error::EndOfInput));
+
+    let (mut prompter, _) = scripted("");
+    assert_eq!(prompter.ask(&Prompt::<i32>::new("N: ")), Err(Error::EndOfInput));
+}
+
+#[test]
+fn history_answers_can_be_repeated() {
+    let (mut prompter, screen) = scripted("history\n3\n4\n!!\n!1\n!7\nhistory\n!2\n");
+    assert_eq!(prompter.ask(&Prompt::new("A: ")), Ok(3));
+    assert_eq!(prompter.ask(&Prompt::new("B: ")), Ok(4));
+    assert_eq!(prompter.ask(&Prompt::new("C: ")), Ok(4));
+    assert_eq!(prompter.ask(&Prompt::new("D: ")), Ok(3));
+    assert_eq!(prompter.ask(&Prompt::new("E: ")), Ok(4));
+    assert_eq!(prompter.history, ["3", "4", "4", "3", "4"]);
+    let text = screen.text();
+    assert!(text.starts_with("A: history\nNo answers yet.\nA: 3\n"));
+    assert!(text.contains("C: !!\n4\n"));
+    assert!(text.contains("E: !7\nNo answer !7 in the history.\n"));
+    assert!(text.contains("   1  3\n   2  4\n   3  4\n   4  3\n"));
+}
+
+#[test]
+fn recorded_sessions_replay() {
+    let path = temp_path("history.txt");
+    let _ = fs::remove_file(&path);
+
+    let (prompter, _) = scripted("x\n12\n\n!1\n");
+    let mut prompter = prompter.record_to(&path).unwrap();
+    assert_eq!(prompter.ask(&Prompt::new("A: ")), Ok(12));
+    assert_eq!(prompter.ask(&Prompt::new("B: ").with_default(5)), Ok(5));
+    assert_eq!(prompter.ask(&Prompt::new("C: ")), Ok(12));
+    drop(prompter);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "12\n\n12\n");
+
+    let screen = Screen::default();
+    let mut replay = Prompter::from_script(&path).unwrap().with_output(Box::new(screen.clone()));
+    assert!(replay.is_script());
+    assert_eq!(replay.ask(&Prompt::new("A: ")), Ok(12));
+    assert_eq!(replay.ask(&Prompt::new("B: ").with_default(5)), Ok(5));
+    assert_eq!(replay.ask(&Prompt::new("C: ")), Ok(12));
+    assert_eq!(replay.ask(&Prompt::<i32>::new("D: ")), Err(Error::EndOfInput));
+    assert_eq!(screen.text(), "A: 12\nB [5]: \nC: 12\n");
+    fs::remove_file(&path).unwrap();
+
+    assert!(matches!(Prompter::from_script(&temp_path("missing.txt")), Err(Error::Io(_))));
+}
+
+#[test]
+fn args_check_options_and_ask_for_the_rest() {
+    let (prompter, _) = scripted("0\n4\n");
+    let words = ["queens", "--rows", "0", "--n", "x"];
+    let args = Args::parse(words.iter().map(|word| word.to_string()).collect(), &[]).with_prompter(prompter);
+    assert_eq!(
+        args.or_ask("rows", Prompt::<usize>::new("# Rows: ").min(1)).unwrap_err().to_string(),
+        "--rows: 0 is out of range, expected at least 1"
+    );
+    assert_eq!(
+        args.or_ask("n", Prompt::<usize>::new("N: ")).unwrap_err().to_string(),
+        "\"x\" is not a valid value for --n"
+    );
+    assert_eq!(args.or_ask("cols", Prompt::new("# Cols: ").min(1)), Ok(4usize));
+    assert_eq!(args.positional_or_ask(1, "max", Prompt::<i32>::new("Max: ")), Err(Error::EndOfInput));
+}
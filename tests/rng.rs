@@ -0,0 +1,326 @@
+// Known-answer tests for the generators. The expected values come from the
+// reference implementations of each algorithm.
+
+use std::collections::HashSet;
+use std::thread;
+
+use algo::error::Error;
+use algo::rng::{
+    make_source, Pcg32, Prng, RandomSource, Sampling, SplitMix64, Streams, Xoshiro256StarStar,
+    SOURCE_NAMES,
+};
+
+
+
+#[test]
+fn lcg_known_answers() {
+    let mut prng = Prng::from_seed(1337);
+    let expected: [u32; 5] = [78628734, 1460962527, 934458668, 1985133557, 721214858];
+    for value in expected {
+        assert_eq!(prng.next_u32(), value);
+    }
+}
+
+#[test]
+fn lcg_next_u64_fills_all_64_bits() {
+    let mut prng = Prng::from_seed(1337);
+    let (hi, mid, lo) = (78628734u64, 1460962527u64, 934458668u64);
+    assert_eq!(prng.next_u64(), (hi << 33) | (mid << 2) | (lo >> 29));
+
+    let mut seen = 0u64;
+    for _ in 0..100 {
+        seen |= prng.next_u64();
+    }
+    assert_eq!(seen, u64::MAX);
+}
+
+#[test]
+fn splitmix64_known_answers() {
+    let mut rng = SplitMix64::from_seed(1234567);
+    let expected: [u64; 5] = [
+        6457827717110365317,
+        3203168211198807973,
+        9817491932198370423,
+        4593380528125082431,
+        16408922859458223821,
+    ];
+    for value in expected {
+        assert_eq!(rng.next_u64(), value);
+    }
+}
+
+#[test]
+fn xoshiro256starstar_known_answers() {
+    let mut rng = Xoshiro256StarStar::from_state([1, 2, 3, 4]);
+    let expected: [u64; 10] = [
+        11520,
+        0,
+        1509978240,
+        1215971899390074240,
+        1216172134540287360,
+        607988272756665600,
+        16172922978634559625,
+        8476171486693032832,
+        10595114339597558777,
+        2904607092377533576,
+    ];
+    for value in expected {
+        assert_eq!(rng.next_u64(), value);
+    }
+}
+
+#[test]
+fn xoshiro256starstar_seeds_through_splitmix64() {
+    let mut splitmix = SplitMix64::from_seed(1234567);
+    let state = [
+        splitmix.next_u64(),
+        splitmix.next_u64(),
+        splitmix.next_u64(),
+        splitmix.next_u64(),
+    ];
+    assert_eq!(Xoshiro256StarStar::from_seed(1234567).state, state);
+}
+
+#[test]
+fn pcg32_known_answers() {
+    // pcg32_srandom(42, 54), as in the pcg32-demo program.
+    let mut rng = Pcg32::new(42, 54);
+    let expected: [u32; 6] = [
+        0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e,
+    ];
+    for value in expected {
+        assert_eq!(rng.next_u32(), value);
+    }
+}
+
+#[test]
+fn fill_bytes_uses_little_endian_words() {
+    let mut expected = SplitMix64::from_seed(99);
+    let first = expected.next_u64().to_le_bytes();
+    let second = expected.next_u64().to_le_bytes();
+
+    let mut bytes = [0u8; 11];
+    SplitMix64::from_seed(99).fill_bytes(&mut bytes);
+    assert_eq!(bytes[..8], first);
+    assert_eq!(bytes[8..], second[..3]);
+}
+
+#[test]
+fn make_source_knows_every_name() {
+    for name in SOURCE_NAMES {
+        let mut a = make_source(name, 7).unwrap();
+        let mut b = make_source(name, 7).unwrap();
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        let f = a.next_f64();
+        assert!((0.0..1.0).contains(&f));
+    }
+    assert!(make_source("mersenne", 7).is_none());
+}
+
+#[test]
+fn ranges_stay_in_bounds_and_reach_both_ends() {
+    let mut rng = SplitMix64::from_seed(1);
+    let mut seen = [false; 7];
+    for _ in 0..1000 {
+        let x = rng.range_i32(-3, 4);
+        assert!((-3..4).contains(&x));
+        seen[(x + 3) as usize] = true;
+    }
+    assert!(seen.iter().all(|s| *s));
+
+    for _ in 0..1000 {
+        let x = rng.range_i64(i64::MIN, i64::MAX);
+        assert!(x < i64::MAX);
+        let y = rng.range_u128(u128::MAX - 5, u128::MAX);
+        assert!((u128::MAX - 5..u128::MAX).contains(&y));
+    }
+    assert_eq!(rng.range_u64(9, 10), 9);
+}
+
+#[test]
+fn single_value_ranges_return_that_value() {
+    let mut rng = SplitMix64::from_seed(1);
+    assert_eq!(rng.below_u64(1), 0);
+    assert_eq!(rng.below_u128(1), 0);
+    assert_eq!(rng.range_u64(5, 6), 5);
+    assert_eq!(rng.range_u64(u64::MAX - 1, u64::MAX), u64::MAX - 1);
+    assert_eq!(rng.range_u128(5, 6), 5);
+    assert_eq!(rng.range_u128(u128::MAX - 1, u128::MAX), u128::MAX - 1);
+    assert_eq!(rng.range_i64(-5, -4), -5);
+    assert_eq!(rng.range_i64(i64::MIN, i64::MIN + 1), i64::MIN);
+    assert_eq!(rng.range_i32(-5, -4), -5);
+    assert_eq!(rng.range_i32(i32::MAX - 1, i32::MAX), i32::MAX - 1);
+}
+
+#[test]
+fn below_u64_is_unbiased_for_awkward_bounds() {
+    // 3 does not divide 2^64, so folding would favour 0.
+    let mut rng = Pcg32::from_seed(3);
+    let mut counts = [0u32; 3];
+    for _ in 0..30000 {
+        counts[rng.below_u64(3) as usize] += 1;
+    }
+    for count in counts {
+        assert!((9500..10500).contains(&count), "{counts:?}");
+    }
+}
+
+#[test]
+fn shuffle_is_a_permutation() {
+    let mut rng = Xoshiro256StarStar::from_seed(5);
+    let mut vec: Vec<i32> = (0..100).collect();
+    rng.shuffle(&mut vec);
+    assert_ne!(vec, (0..100).collect::<Vec<i32>>());
+    vec.sort();
+    assert_eq!(vec, (0..100).collect::<Vec<i32>>());
+}
+
+#[test]
+fn sample_picks_distinct_indices() {
+    let mut rng = Prng::from_seed(11);
+    let picked = rng.sample(50, 20);
+    assert_eq!(picked.len(), 20);
+    assert!(picked.iter().all(|i| *i < 50));
+    assert_eq!(picked.iter().collect::<HashSet<_>>().len(), 20);
+    assert_eq!(rng.sample(5, 5).iter().collect::<HashSet<_>>().len(), 5);
+}
+
+#[test]
+fn choose_weighted_skips_zero_weights() {
+    let mut rng = SplitMix64::from_seed(2);
+    let weights = [0.0, 3.0, 0.0, 1.0];
+    let mut counts = [0u32; 4];
+    for _ in 0..4000 {
+        counts[rng.choose_weighted(&weights)] += 1;
+    }
+    assert_eq!(counts[0] + counts[2], 0);
+    assert!((2800..3200).contains(&counts[1]), "{counts:?}");
+}
+
+#[test]
+fn normal_and_exponential_have_the_right_moments() {
+    let mut rng = Xoshiro256StarStar::from_seed(8);
+    let n = 20000;
+    let normals: Vec<f64> = (0..n).map(|_| rng.next_normal(10.0, 2.0)).collect();
+    let mean = normals.iter().sum::<f64>() / n as f64;
+    let var = normals.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n as f64;
+    assert!((mean - 10.0).abs() < 0.1, "{mean}");
+    assert!((var.sqrt() - 2.0).abs() < 0.1, "{var}");
+
+    let exp_mean = (0..n).map(|_| rng.next_exponential(4.0)).sum::<f64>() / n as f64;
+    assert!((exp_mean - 0.25).abs() < 0.01, "{exp_mean}");
+}
+
+// Step a generator k times the slow way.
+fn step<R: RandomSource>(rng: &mut R, k: u64) {
+    for _ in 0..k {
+        rng.next_u32();
+    }
+}
+
+#[test]
+fn jump_matches_stepping() {
+    for k in [0, 1, 2, 3, 1000, 12345] {
+        let (mut a, mut b) = (Prng::from_seed(1337), Prng::from_seed(1337));
+        a.jump(k);
+        step(&mut b, k);
+        assert_eq!(a.next_u32(), b.next_u32());
+
+        let (mut a, mut b) = (SplitMix64::from_seed(7), SplitMix64::from_seed(7));
+        a.jump(k);
+        for _ in 0..k {
+            b.next_u64();
+        }
+        assert_eq!(a.next_u64(), b.next_u64());
+
+        let (mut a, mut b) = (Pcg32::new(42, 54), Pcg32::new(42, 54));
+        a.jump(k);
+        step(&mut b, k);
+        assert_eq!(a.next_u32(), b.next_u32());
+    }
+}
+
+#[test]
+fn lcg_jump_wraps_at_its_period() {
+    let mut prng = Prng::from_seed(99);
+    prng.jump(1 << 31);
+    assert_eq!(prng.seed, 99);
+}
+
+#[test]
+fn xoshiro_jump_known_answer() {
+    // T^(2^128) applied to [1, 2, 3, 4], computed with GF(2) matrix powers.
+    let mut rng = Xoshiro256StarStar::from_state([1, 2, 3, 4]);
+    rng.jump();
+    assert_eq!(
+        rng.state,
+        [
+            10122426448480695249,
+            8079205330032121950,
+            7289065458748526725,
+            9477464255293849680,
+        ]
+    );
+}
+
+#[test]
+fn split_streams_start_where_expected() {
+    let prng = Prng::from_seed(5);
+    let mut jumped = prng.clone();
+    jumped.jump(3 * Prng::STREAM_LENGTH);
+    assert_eq!(prng.split(3).unwrap().seed, jumped.seed);
+    assert_eq!(prng.split(0).unwrap().seed, prng.seed);
+
+    let splitmix = SplitMix64::from_seed(5);
+    let gamma: u64 = 0x9e37_79b9_7f4a_7c15;
+    assert_eq!(splitmix.split(2).unwrap().state, 5u64.wrapping_add((2u64 << 40).wrapping_mul(gamma)));
+
+    let xoshiro = Xoshiro256StarStar::from_seed(5);
+    let mut twice = xoshiro.clone();
+    twice.jump();
+    twice.jump();
+    assert_eq!(xoshiro.split(2).unwrap().state, twice.state);
+
+    let mut a = Pcg32::from_seed(5).split(1).unwrap();
+    let mut b = Pcg32::from_seed(5).split(2).unwrap();
+    assert_ne!((a.next_u32(), a.next_u32()), (b.next_u32(), b.next_u32()));
+}
+
+#[test]
+fn split_refuses_streams_past_the_last() {
+    let prng = Prng::from_seed(5);
+    assert_eq!(Prng::MAX_STREAM_ID, Prng::NUM_STREAMS - 1);
+    assert!(prng.split(Prng::MAX_STREAM_ID).is_ok());
+    assert!(matches!(prng.split(Prng::NUM_STREAMS), Err(Error::InvalidArgument(_))));
+    assert!(prng.split(u64::MAX).is_err());
+
+    let splitmix = SplitMix64::from_seed(5);
+    assert!(splitmix.split(SplitMix64::MAX_STREAM_ID).is_ok());
+    assert!(matches!(splitmix.split(1 << 24), Err(Error::InvalidArgument(_))));
+
+    let pcg = Pcg32::from_seed(5);
+    assert!(pcg.split(Pcg32::MAX_STREAM_ID).is_ok());
+    assert!(matches!(pcg.split(1 << 63), Err(Error::InvalidArgument(_))));
+
+    let xoshiro = Xoshiro256StarStar::from_seed(5);
+    let last = xoshiro.split(Xoshiro256StarStar::MAX_STREAM_ID).unwrap();
+    assert_ne!(last.state, xoshiro.state);
+    assert!(matches!(xoshiro.split(1 << 16), Err(Error::InvalidArgument(_))));
+    assert!(matches!(xoshiro.split(u64::MAX), Err(Error::InvalidArgument(_))));
+}
+
+#[test]
+fn streams_do_not_depend_on_thread_scheduling() {
+    let root = Xoshiro256StarStar::from_seed(2024);
+    let sequential: Vec<u64> = (0..4).map(|i| root.split(i).unwrap().next_u64()).collect();
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let mut rng = root.split(i).unwrap();
+            thread::spawn(move || rng.next_u64())
+        })
+        .collect();
+    let threaded: Vec<u64> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(sequential, threaded);
+}
@@ -0,0 +1,49 @@
+// The quality battery is a gate for the generators: the strong ones must
+// pass every test, and the LCG's known weaknesses must show up.
+
+use algo::rng::quality::{
+    bit_frequency, birthday_spacings, chi_square_uniformity, gap, low_bits, run_battery, runs, NUM_SAMPLES,
+};
+use algo::rng::{make_source, Prng, RandomSource, SOURCE_NAMES};
+use algo::stats::{chi_square_p_value, gamma_q, ln_gamma, normal_p_value};
+
+
+
+#[test]
+fn special_functions_match_tables() {
+    assert!((ln_gamma(5.0) - 24.0f64.ln()).abs() < 1e-12);
+    assert!((ln_gamma(0.5) - std::f64::consts::PI.sqrt().ln()).abs() < 1e-12);
+    assert!((gamma_q(1.0, 2.0) - (-2.0f64).exp()).abs() < 1e-12);
+    assert!((normal_p_value(1.959964) - 0.05).abs() < 1e-6);
+    assert!((chi_square_p_value(3.841459, 1.0) - 0.05).abs() < 1e-6);
+    assert!((chi_square_p_value(18.307038, 10.0) - 0.05).abs() < 1e-6);
+}
+
+#[test]
+fn strong_generators_pass_the_battery() {
+    for name in SOURCE_NAMES.iter().filter(|name| **name != "lcg") {
+        let mut rng = make_source(name, 1337).unwrap();
+        for result in run_battery(&mut rng, NUM_SAMPLES) {
+            assert!(result.passed, "{name} failed {} (p = {})", result.name, result.p_value);
+        }
+    }
+}
+
+#[test]
+fn lcg_weaknesses_are_detected() {
+    // The LCG only produces 31 bits, and the tests of its high bits
+    // scale to those, so they pass.
+    assert_eq!(Prng::from_seed(1337).output_bits(), 31);
+    assert!(chi_square_uniformity(&mut Prng::from_seed(1337), NUM_SAMPLES).passed);
+    assert!(runs(&mut Prng::from_seed(1337), NUM_SAMPLES).passed);
+    assert!(gap(&mut Prng::from_seed(1337), NUM_SAMPLES).passed);
+
+    // Its low k bits repeat every 2^k steps: the lowest bit flips on every
+    // step, and the low 4 bits of successive outputs form only 16 pairs.
+    let mut prng = Prng::from_seed(1337);
+    let lowest: Vec<u32> = (0..8).map(|_| prng.next_u32() & 1).collect();
+    assert!(lowest.windows(2).all(|pair| pair[0] != pair[1]));
+    assert!(!bit_frequency(&mut Prng::from_seed(1337), NUM_SAMPLES).passed);
+    assert!(!low_bits(&mut Prng::from_seed(1337), NUM_SAMPLES).passed);
+    assert!(!birthday_spacings(&mut Prng::from_seed(1337), NUM_SAMPLES).passed);
+}
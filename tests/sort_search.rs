@@ -0,0 +1,572 @@
+// Property tests for the sorts and searches: on random vectors of every
+// size, with heavy duplicates, extreme values and adversarial orderings,
+// the sorts must return a sorted permutation of their input, counting sort
+// must be stable, and the searches must agree with each other and with
+// slice::binary_search.
+
+use std::fmt::Debug;
+
+use algo::check::{assert_property, shrink_i64, shrink_vec};
+use algo::metrics::{Counter, Metrics};
+use algo::rng::{RandomSource, Sampling, SplitMix64};
+use algo::searching::{binary_search, linear_search};
+use algo::sorting::{
+    bubble_sort, bubble_sort_by, bubble_sort_by_key, counting_sort, counting_sort_by_key,
+    counting_sort_by_key_in_place, descending, heapsort, heapsort_by_key, insertion_sort, insertion_sort_by_key,
+    is_sorted, is_sorted_by, is_sorted_by_key, lsd_radix_sort, lsd_radix_sort_by_key, msd_radix_sort,
+    msd_radix_sort_by_key, partition_bentley_mcilroy_by, partition_by, partition_dijkstra_by, quicksort, quicksort_by,
+    quicksort_by_key, quicksort_with, quicksort_with_by_key, reversed, Customer, Digits, Partition, RadixKey,
+};
+
+const SEED: u64 = 2024;
+const NUM_CASES: usize = 300;
+const EXTREMES: [i32; 5] = [i32::MIN, -1, 0, 1, i32::MAX];
+
+
+
+// A vector of 0 to 200 values: mostly short, with values from the whole
+// i32 range, a handful of distinct values, or only the extremes, in
+// random, sorted, reversed, organ-pipe or constant order.
+fn generate_vec(prng: &mut SplitMix64) -> Vec<i32> {
+    let len = match prng.range_i32(0, 4) {
+        0 => prng.range_i32(0, 3),
+        1 => prng.range_i32(0, 16),
+        _ => prng.range_i32(0, 201),
+    } as usize;
+    let kind = prng.range_i32(0, 3);
+    let mut vec: Vec<i32> = Vec::with_capacity(len);
+    for _ in 0..len {
+        vec.push(match kind {
+            0 => prng.next_u32() as i32,
+            1 => prng.range_i32(0, 4),
+            _ => EXTREMES[prng.range_i32(0, EXTREMES.len() as i32) as usize],
+        });
+    }
+    match prng.range_i32(0, 5) {
+        0 => vec.sort(),
+        1 => vec.sort_by(|a, b| b.cmp(a)),
+        2 => {
+            vec.sort();
+            let (mut left, mut right): (Vec<i32>, Vec<i32>) = (vec![], vec![]);
+            for i in 0..vec.len() {
+                if i % 2 == 0 {
+                    left.push(vec[i]);
+                } else {
+                    right.push(vec[i]);
+                }
+            }
+            right.reverse();
+            left.extend(right);
+            vec = left;
+        }
+        3 if !vec.is_empty() => {
+            let value = vec[0];
+            vec.fill(value);
+        }
+        _ => {}
+    }
+    return vec;
+}
+
+fn shrink_values(vec: &Vec<i32>) -> Vec<Vec<i32>> {
+    return shrink_vec(vec, |&value| shrink_i64(value as i64, 0).into_iter().map(|value| value as i32).collect());
+}
+
+// Check that sorted is the input in non-decreasing order.
+fn check_sort(name: &str, input: &Vec<i32>, sorted: &Vec<i32>) -> Result<(), String> {
+    for i in 1..sorted.len() {
+        if sorted[i - 1] > sorted[i] {
+            return Err(format!("{name} left {} before {} at index {i}", sorted[i - 1], sorted[i]));
+        }
+    }
+    let mut expected = input.clone();
+    expected.sort();
+    if *sorted != expected {
+        return Err(format!("{name} returned {sorted:?}, which is not a permutation of the input"));
+    }
+    return Ok(());
+}
+
+#[test]
+fn bubble_sort_sorts() {
+    assert_property(SEED, NUM_CASES, generate_vec, shrink_values, |input| {
+        let mut vec = input.clone();
+        bubble_sort(&mut vec, &Metrics::new());
+        return check_sort("bubble_sort", input, &vec);
+    });
+}
+
+#[test]
+fn quicksort_sorts() {
+    assert_property(SEED, NUM_CASES, generate_vec, shrink_values, |input| {
+        let mut vec = input.clone();
+        quicksort(&mut vec, &Metrics::new());
+        return check_sort("quicksort", input, &vec);
+    });
+}
+
+#[test]
+fn quicksort_sorts_with_every_partition() {
+    for partition in Partition::ALL {
+        assert_property(SEED, NUM_CASES, generate_vec, shrink_values, |input| {
+            let mut vec = input.clone();
+            quicksort_with(&mut vec, partition, &Metrics::new());
+            return check_sort(partition.name(), input, &vec);
+        });
+    }
+}
+
+
+
+// Every partition leaves slices too short to split as they are.
+#[test]
+fn partitions_accept_short_slices() {
+    for input in [vec![], vec![7]] {
+        let mut vec: Vec<i32> = input.clone();
+        assert_eq!(partition_by(&mut vec, &mut i32::cmp, &Metrics::new()), 0);
+        assert_eq!(partition_dijkstra_by(&mut vec, &mut i32::cmp, &Metrics::new()), (0, vec.len()));
+        assert_eq!(partition_bentley_mcilroy_by(&mut vec, &mut i32::cmp, &Metrics::new()), (0, vec.len()));
+        assert_eq!(vec, input);
+    }
+}
+
+type PartitionFn = fn(&mut Vec<i32>) -> (usize, usize);
+
+// The three-way partitions split around the first element into keys less
+// than it, equal to it and greater, keeping every key.
+#[test]
+fn three_way_partitions_group_equal_keys() {
+    let partitions: [(&str, PartitionFn); 2] = [
+        ("dijkstra", |vec| partition_dijkstra_by(vec, &mut i32::cmp, &Metrics::new())),
+        ("bentley-mcilroy", |vec| partition_bentley_mcilroy_by(vec, &mut i32::cmp, &Metrics::new())),
+    ];
+    for (name, partition) in partitions {
+        assert_property(SEED, NUM_CASES, generate_vec, shrink_values, |input| {
+            let mut vec = input.clone();
+            let (lt, gt) = partition(&mut vec);
+            let mut sorted = vec.clone();
+            sorted.sort();
+            check_sort(name, input, &sorted)?;
+            if input.is_empty() {
+                return if (lt, gt) == (0, 0) { Ok(()) } else { Err(format!("{name} returned {lt}..{gt}")) };
+            }
+            let pivot = input[0];
+            let grouped = lt < gt
+                && vec[..lt].iter().all(|&value| value < pivot)
+                && vec[lt..gt].iter().all(|&value| value == pivot)
+                && vec[gt..].iter().all(|&value| value > pivot);
+            if !grouped {
+                return Err(format!("{name} split {vec:?} at {lt}..{gt} around {pivot}"));
+            }
+            return Ok(());
+        });
+    }
+}
+
+#[test]
+fn insertion_sort_sorts() {
+    assert_property(SEED, NUM_CASES, generate_vec, shrink_values, |input| {
+        let mut vec = input.clone();
+        insertion_sort(&mut vec, &Metrics::new());
+        return check_sort("insertion_sort", input, &vec);
+    });
+}
+
+#[test]
+fn heapsort_sorts() {
+    assert_property(SEED, NUM_CASES, generate_vec, shrink_values, |input| {
+        let mut vec = input.clone();
+        heapsort(&mut vec, &Metrics::new());
+        return check_sort("heapsort", input, &vec);
+    });
+}
+
+#[test]
+fn sorts_follow_the_comparator() {
+    assert_property(SEED, NUM_CASES, generate_vec, shrink_values, |input| {
+        let mut expected = input.clone();
+        expected.sort_by(|a, b| b.cmp(a));
+        let mut vec = input.clone();
+        bubble_sort_by(&mut vec, descending, &Metrics::new());
+        if vec != expected {
+            return Err(format!("bubble_sort_by returned {vec:?}"));
+        }
+        let mut vec = input.clone();
+        quicksort_by(&mut vec, reversed(i32::cmp), &Metrics::new());
+        if vec != expected {
+            return Err(format!("quicksort_by returned {vec:?}"));
+        }
+        if !is_sorted_by(&vec, descending) || is_sorted(&vec) != (vec.first() == vec.last()) {
+            return Err(format!("is_sorted_by disagrees on {vec:?}"));
+        }
+        return Ok(());
+    });
+}
+
+#[test]
+fn sorts_take_keys_and_elements_that_do_not_copy() {
+    let keys = [3, 1, 2, 1, 3, 0, 2, 1];
+    let customers = || -> Vec<Customer> {
+        (0..keys.len()).map(|i| Customer { id: format!("C{i}"), num_purchases: keys[i] }).collect()
+    };
+    let ids = |customers: &Vec<Customer>| customers.iter().map(|customer| customer.id.clone()).collect::<Vec<_>>();
+
+    // Bubble and insertion sort are stable, so customers with the same key
+    // stay in order.
+    let mut sorted = customers();
+    bubble_sort_by_key(&mut sorted, |customer| customer.num_purchases, &Metrics::new());
+    assert_eq!(ids(&sorted), ["C5", "C1", "C3", "C7", "C2", "C6", "C0", "C4"]);
+    let mut sorted = customers();
+    insertion_sort_by_key(&mut sorted, |customer| customer.num_purchases, &Metrics::new());
+    assert_eq!(ids(&sorted), ["C5", "C1", "C3", "C7", "C2", "C6", "C0", "C4"]);
+    let mut sorted = customers();
+    heapsort_by_key(&mut sorted, |customer| customer.num_purchases, &Metrics::new());
+    assert!(is_sorted_by_key(&sorted, |customer| customer.num_purchases));
+    let mut sorted = customers();
+    quicksort_with_by_key(&mut sorted, Partition::BentleyMcIlroy, |customer| customer.num_purchases, &Metrics::new());
+    assert!(is_sorted_by_key(&sorted, |customer| customer.num_purchases));
+
+    let mut sorted = customers();
+    quicksort_by_key(&mut sorted, |customer| customer.num_purchases, &Metrics::new());
+    assert!(is_sorted_by_key(&sorted, |customer| customer.num_purchases));
+    let mut sorted_keys: Vec<i32> = sorted.iter().map(|customer| customer.num_purchases).collect();
+    sorted_keys.dedup();
+    assert_eq!(sorted_keys, [0, 1, 2, 3]);
+
+    // Most purchases first, ties broken by id.
+    let mut sorted = customers();
+    quicksort_by(
+        &mut sorted,
+        |a, b| b.num_purchases.cmp(&a.num_purchases).then_with(|| a.id.cmp(&b.id)),
+        &Metrics::new(),
+    );
+    assert_eq!(ids(&sorted), ["C0", "C4", "C2", "C6", "C1", "C3", "C7", "C5"]);
+
+    let mut words: Vec<String> = ["pear", "fig", "apple", "kiwi", "banana"].map(String::from).to_vec();
+    quicksort(&mut words, &Metrics::new());
+    assert_eq!(words, ["apple", "banana", "fig", "kiwi", "pear"]);
+    bubble_sort_by_key(&mut words, |word| std::cmp::Reverse(word.len()), &Metrics::new());
+    assert_eq!(words, ["banana", "apple", "kiwi", "pear", "fig"]);
+    assert!(is_sorted_by_key(&words, |word| std::cmp::Reverse(word.len())));
+    assert!(!is_sorted(&words));
+    assert!(is_sorted::<String>(&[]));
+}
+
+// Keys below max and the max itself, for counting sort.
+fn generate_keys(prng: &mut SplitMix64) -> (Vec<i32>, i32) {
+    let max = match prng.range_i32(0, 3) {
+        0 => 1,
+        1 => prng.range_i32(1, 8),
+        _ => prng.range_i32(1, 1000),
+    };
+    let len = prng.range_i32(0, 201);
+    let keys: Vec<i32> = (0..len).map(|_| prng.range_i32(0, max)).collect();
+    return (keys, max);
+}
+
+fn shrink_keys((keys, max): &(Vec<i32>, i32)) -> Vec<(Vec<i32>, i32)> {
+    let mut candidates: Vec<(Vec<i32>, i32)> = vec![];
+    for keys in shrink_vec(keys, |&key| shrink_i64(key as i64, 0).into_iter().map(|key| key as i32).collect()) {
+        candidates.push((keys, *max));
+    }
+    // A smaller max is only a candidate if every key still fits under it.
+    let smallest_max = keys.iter().max().map_or(1, |key| key + 1);
+    for max in shrink_i64(*max as i64, smallest_max as i64) {
+        candidates.push((keys.clone(), max as i32));
+    }
+    return candidates;
+}
+
+#[test]
+fn counting_sort_is_stable() {
+    assert_property(SEED, NUM_CASES, generate_keys, shrink_keys, |(keys, max)| {
+        let customers: Vec<Customer> = (0..keys.len())
+            .map(|i| Customer { id: format!("C{i}"), num_purchases: keys[i] })
+            .collect();
+        let sorted = counting_sort(&customers, *max).map_err(|error| error.to_string())?;
+        let sorted_keys: Vec<i32> = sorted.iter().map(|customer| customer.num_purchases).collect();
+        check_sort("counting_sort", keys, &sorted_keys)?;
+
+        // Customers with the same key must keep their input order, and each
+        // must still carry its own key.
+        let positions: Vec<usize> = sorted.iter().map(|customer| customer.id[1..].parse().unwrap()).collect();
+        for i in 0..sorted.len() {
+            if keys[positions[i]] != sorted[i].num_purchases {
+                return Err(format!("customer {} has key {}, not {}", sorted[i].id, sorted[i].num_purchases, keys[positions[i]]));
+            }
+            if i > 0 && sorted[i - 1].num_purchases == sorted[i].num_purchases && positions[i - 1] > positions[i] {
+                return Err(format!("{} came before {} with the same key", sorted[i - 1].id, sorted[i].id));
+            }
+        }
+        return Ok(());
+    });
+}
+
+#[test]
+fn counting_sort_by_key_is_stable_for_any_key_range() {
+    assert_property(SEED, NUM_CASES, generate_keys, shrink_keys, |(keys, _)| {
+        // (key, position) pairs, with keys shifted to be partly negative.
+        let pairs: Vec<(i64, usize)> = (0..keys.len()).map(|i| (keys[i] as i64 - 500, i)).collect();
+        let mut expected = pairs.clone();
+        expected.sort_by_key(|pair| pair.0);
+        let sorted = counting_sort_by_key(&pairs, |pair| pair.0).map_err(|error| error.to_string())?;
+        if sorted != expected {
+            return Err(format!("counting_sort_by_key returned {sorted:?}"));
+        }
+        let mut sorted = pairs.clone();
+        counting_sort_by_key_in_place(&mut sorted, |pair| pair.0).map_err(|error| error.to_string())?;
+        if sorted != expected {
+            return Err(format!("counting_sort_by_key_in_place returned {sorted:?}"));
+        }
+        return Ok(());
+    });
+
+    // Only the range between the smallest and largest key matters.
+    let keys = [i64::MAX, i64::MAX - 2, i64::MAX - 1, i64::MAX - 2];
+    assert_eq!(counting_sort_by_key(&keys, |&key| key).unwrap(), [i64::MAX - 2, i64::MAX - 2, i64::MAX - 1, i64::MAX]);
+    let keys = [i64::MIN + 1, i64::MIN, i64::MIN + 1];
+    assert_eq!(counting_sort_by_key(&keys, |&key| key).unwrap(), [i64::MIN, i64::MIN + 1, i64::MIN + 1]);
+
+    // Elements that cannot be cloned are moved.
+    struct Ticket(i64, String);
+    let mut tickets: Vec<Ticket> = [(3, "a"), (-1, "b"), (3, "c"), (0, "d")]
+        .map(|(key, name)| Ticket(key, String::from(name)))
+        .into();
+    counting_sort_by_key_in_place(&mut tickets, |ticket| ticket.0).unwrap();
+    let names: Vec<&str> = tickets.iter().map(|ticket| ticket.1.as_str()).collect();
+    assert_eq!(names, ["b", "d", "a", "c"]);
+}
+
+// Check that LSD radix sort puts the values in the same order as
+// slice::sort, whatever the integer type, with either digit size.
+fn check_lsd_radix_sort<K: RadixKey + Ord + Debug>(input: &Vec<i32>, cast: fn(i32) -> K) -> Result<(), String> {
+    let values: Vec<K> = input.iter().map(|&value| cast(value)).collect();
+    let mut expected = values.clone();
+    expected.sort();
+    for digits in Digits::ALL {
+        let mut sorted = values.clone();
+        lsd_radix_sort(&mut sorted, digits, &Metrics::new()).map_err(|error| error.to_string())?;
+        if sorted != expected {
+            return Err(format!("lsd_radix_sort with {}-bit digits returned {sorted:?}", digits.name()));
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn lsd_radix_sort_sorts_every_integer_type() {
+    assert_property(SEED, NUM_CASES, generate_vec, shrink_values, |input| {
+        check_lsd_radix_sort(input, |value| value)?;
+        check_lsd_radix_sort(input, |value| value as i8)?;
+        check_lsd_radix_sort(input, |value| value as i16)?;
+        check_lsd_radix_sort(input, |value| value as i64 * i32::MAX as i64)?;
+        check_lsd_radix_sort(input, |value| value as u8)?;
+        check_lsd_radix_sort(input, |value| value as u16)?;
+        check_lsd_radix_sort(input, |value| value as u32)?;
+        check_lsd_radix_sort(input, |value| (value as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15))?;
+        return Ok(());
+    });
+
+    let mut extremes = vec![i64::MAX, 0, i64::MIN, -1, 1, i64::MIN + 1, i64::MAX - 1];
+    lsd_radix_sort(&mut extremes, Digits::Bits11, &Metrics::new()).unwrap();
+    assert_eq!(extremes, [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX]);
+}
+
+#[test]
+fn lsd_radix_sort_by_key_is_stable() {
+    assert_property(SEED, NUM_CASES, generate_keys, shrink_keys, |(keys, _)| {
+        let pairs: Vec<(i32, usize)> = (0..keys.len()).map(|i| (keys[i] - 500, i)).collect();
+        let mut expected = pairs.clone();
+        expected.sort_by_key(|pair| pair.0);
+        for digits in Digits::ALL {
+            let mut sorted = pairs.clone();
+            lsd_radix_sort_by_key(&mut sorted, digits, |pair| pair.0, &Metrics::new())
+                .map_err(|error| error.to_string())?;
+            if sorted != expected {
+                return Err(format!("lsd_radix_sort_by_key with {}-bit digits returned {sorted:?}", digits.name()));
+            }
+        }
+        return Ok(());
+    });
+}
+
+#[test]
+fn lsd_radix_sort_skips_digits_every_key_shares() {
+    // Keys below 2¹⁶ in a u64 need two byte passes, not eight.
+    let mut prng = SplitMix64::from_seed(SEED);
+    let mut vec: Vec<u64> = (0..1000).map(|_| prng.next_u32() as u64 & 0xffff).collect();
+    let metrics = Metrics::new();
+    lsd_radix_sort(&mut vec, Digits::Bytes, &metrics).unwrap();
+    assert!(is_sorted(&vec));
+    assert_eq!(metrics.get(Counter::Calls), 2);
+
+    // Nothing varies in a constant vector, so no pass is needed.
+    let mut vec = vec![-7i32; 100];
+    let metrics = Metrics::new();
+    lsd_radix_sort(&mut vec, Digits::Bits11, &metrics).unwrap();
+    assert_eq!(metrics.get(Counter::Calls), 0);
+    assert_eq!(vec, [-7; 100]);
+}
+
+// Byte strings from a small alphabet, so many share prefixes, are equal
+// or are prefixes of each other.
+fn generate_strings(prng: &mut SplitMix64) -> Vec<Vec<u8>> {
+    let len = prng.range_i32(0, 201);
+    let max_len = prng.range_i32(1, 12);
+    return (0..len)
+        .map(|_| {
+            let n = prng.range_i32(0, max_len);
+            return (0..n).map(|_| [0u8, b'a', b'b', 255][prng.range_i32(0, 4) as usize]).collect();
+        })
+        .collect();
+}
+
+fn shrink_strings(strings: &Vec<Vec<u8>>) -> Vec<Vec<Vec<u8>>> {
+    return shrink_vec(strings, |string| {
+        if string.is_empty() {
+            return vec![];
+        }
+        return vec![string[1..].to_vec(), string[..string.len() - 1].to_vec()];
+    });
+}
+
+#[test]
+fn msd_radix_sort_sorts_byte_strings() {
+    assert_property(SEED, NUM_CASES, generate_strings, shrink_strings, |input| {
+        let mut expected = input.clone();
+        expected.sort();
+        let mut sorted = input.clone();
+        msd_radix_sort(&mut sorted, &Metrics::new()).map_err(|error| error.to_string())?;
+        if sorted != expected {
+            return Err(format!("msd_radix_sort returned {sorted:?}"));
+        }
+
+        // Tagged with their positions, equal strings must keep their order.
+        let mut tagged: Vec<(Vec<u8>, usize)> = (0..input.len()).map(|i| (input[i].clone(), i)).collect();
+        let mut expected = tagged.clone();
+        expected.sort_by(|a, b| a.0.cmp(&b.0));
+        msd_radix_sort_by_key(&mut tagged, |pair| pair.0.as_slice(), &Metrics::new())
+            .map_err(|error| error.to_string())?;
+        if tagged != expected {
+            return Err(format!("msd_radix_sort_by_key returned {tagged:?}"));
+        }
+        return Ok(());
+    });
+
+    let mut words = vec!["banana", "", "band", "ban", "apple", "", "bandana", "b"];
+    msd_radix_sort(&mut words, &Metrics::new()).unwrap();
+    assert_eq!(words, ["", "", "apple", "b", "ban", "banana", "band", "bandana"]);
+
+    let mut customers: Vec<Customer> = (0..100)
+        .map(|i| Customer { id: format!("C{i}"), num_purchases: i })
+        .collect();
+    msd_radix_sort_by_key(&mut customers, |customer| customer.id.as_bytes(), &Metrics::new()).unwrap();
+    assert!(is_sorted_by_key(&customers, |customer| customer.id.clone()));
+    assert_eq!(customers[2].id, "C10");
+}
+
+// A sorted vector and a target that is usually in it, sometimes next to a
+// value in it, and sometimes an extreme.
+fn generate_search(prng: &mut SplitMix64) -> (Vec<i32>, i32) {
+    let mut vec = generate_vec(prng);
+    vec.sort();
+    let target = match prng.range_i32(0, 3) {
+        0 if !vec.is_empty() => vec[prng.range_i32(0, vec.len() as i32) as usize],
+        1 if !vec.is_empty() => vec[prng.range_i32(0, vec.len() as i32) as usize].saturating_add(1),
+        _ => EXTREMES[prng.range_i32(0, EXTREMES.len() as i32) as usize],
+    };
+    return (vec, target);
+}
+
+fn shrink_search((vec, target): &(Vec<i32>, i32)) -> Vec<(Vec<i32>, i32)> {
+    let mut candidates: Vec<(Vec<i32>, i32)> = vec![];
+    for mut vec in shrink_values(vec) {
+        vec.sort();
+        candidates.push((vec, *target));
+    }
+    for target in shrink_i64(*target as i64, 0) {
+        candidates.push((vec.clone(), target as i32));
+    }
+    return candidates;
+}
+
+#[test]
+fn searches_agree() {
+    assert_property(SEED, NUM_CASES, generate_search, shrink_search, |(vec, target)| {
+        let metrics = Metrics::new();
+        let expected = vec.binary_search(target);
+
+        // Linear search finds the first match.
+        let linear = linear_search(vec, *target, &metrics);
+        let first = vec.iter().position(|value| value == target).map_or(-1, |i| i as i32);
+        if linear != first {
+            return Err(format!("linear_search returned {linear}, the first match is {first}"));
+        }
+
+        // Binary search may find any match.
+        let binary = match binary_search(vec, *target, &metrics) {
+            Ok(index) => index,
+            Err(error) => return Err(format!("binary_search failed: {error}")),
+        };
+        if (binary >= 0) != expected.is_ok() || (linear >= 0) != (binary >= 0) {
+            return Err(format!("linear {linear}, binary {binary} and slice {expected:?} disagree"));
+        }
+        if binary >= 0 && vec[binary as usize] != *target {
+            return Err(format!("binary_search returned {binary}, where {} is", vec[binary as usize]));
+        }
+        return Ok(());
+    });
+}
+
+// Worst cases that a random vector is unlikely to hit.
+#[test]
+fn sorts_handle_adversarial_orders() {
+    let sorted: Vec<i32> = (0..500).collect();
+    let reversed: Vec<i32> = (0..500).rev().collect();
+    let constant = vec![7; 500];
+    let sawtooth: Vec<i32> = (0..500).map(|i| i % 7).collect();
+    let extremes: Vec<i32> = (0..500).map(|i| EXTREMES[i % EXTREMES.len()]).collect();
+    for input in [sorted, reversed, constant, sawtooth, extremes] {
+        let mut vec = input.clone();
+        bubble_sort(&mut vec, &Metrics::new());
+        check_sort("bubble_sort", &input, &vec).unwrap();
+        let mut vec = input.clone();
+        quicksort(&mut vec, &Metrics::new());
+        check_sort("quicksort", &input, &vec).unwrap();
+        let mut vec = input.clone();
+        insertion_sort(&mut vec, &Metrics::new());
+        check_sort("insertion_sort", &input, &vec).unwrap();
+        let mut vec = input.clone();
+        heapsort(&mut vec, &Metrics::new());
+        check_sort("heapsort", &input, &vec).unwrap();
+    }
+}
+
+// Orders that made a last-element pivot quadratic, too big for that
+// quicksort's recursion to fit on the stack.
+#[test]
+fn quicksort_stays_n_log_n_on_long_ordered_input() {
+    let n = 200_000;
+    let sorted: Vec<i32> = (0..n).collect();
+    let reversed: Vec<i32> = (0..n).rev().collect();
+    let organ_pipe: Vec<i32> = (0..n).map(|i| if i < n / 2 { i } else { n - i }).collect();
+    let constant = vec![7; n as usize];
+    let few_keys: Vec<i32> = (0..n).map(|i| i % 3).collect();
+    let limit = 3 * n as u64 * (n as f64).log2() as u64;
+    for (name, input) in [
+        ("sorted", sorted),
+        ("reversed", reversed),
+        ("organ pipe", organ_pipe),
+        ("constant", constant),
+        ("few keys", few_keys),
+    ] {
+        for partition in Partition::ALL {
+            let mut vec = input.clone();
+            let metrics = Metrics::new();
+            quicksort_with(&mut vec, partition, &metrics);
+            assert!(is_sorted(&vec), "{name}");
+            let comparisons = metrics.get(Counter::Comparisons);
+            assert!(comparisons < limit, "{} took {comparisons} comparisons on {name}", partition.name());
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// Tests for the call trees recorded by a tracing Metrics.
+
+use algo::backtracking::{hanoi, knight_tour, nqueens};
+use algo::knapsack::{branch_and_bound, exhaustive_search, make_items, sum_weights};
+use algo::metrics::{Counter, Metrics};
+use algo::number_theory::{fibonacci_on_the_fly_traced, fibonacci_traced, gcd_traced};
+use algo::rng::Prng;
+use algo::trace::Tracer;
+
+
+
+fn traced() -> Metrics {
+    return Metrics::with_tracer(Tracer::default());
+}
+
+#[test]
+fn gcd_records_a_chain() {
+    let metrics = traced();
+    assert_eq!(gcd_traced(270, 192, &metrics), Ok(6));
+    let tracer = metrics.take_tracer().unwrap();
+    assert_eq!(
+        tracer.to_text(None),
+        "gcd(270, 192) = 6\n  gcd(192, 78) = 6\n    gcd(78, 36) = 6\n      gcd(36, 6) = 6\n        gcd(6, 0) = 6\n"
+    );
+    assert_eq!(
+        tracer.to_text(Some(1)),
+        "gcd(270, 192) = 6\n  gcd(192, 78) = 6\n    ... 3 calls below depth 1\n"
+    );
+}
+
+#[test]
+fn untraced_metrics_have_no_tracer() {
+    let metrics = Metrics::new();
+    assert_eq!(gcd_traced(12, 18, &metrics), Ok(6));
+    assert!(!metrics.is_tracing());
+    assert!(metrics.take_tracer().is_none());
+}
+
+#[test]
+fn fibonacci_tree_has_one_node_per_call() {
+    let metrics = traced();
+    assert_eq!(fibonacci_traced(10, &metrics), 55);
+    let tracer = metrics.take_tracer().unwrap();
+    assert_eq!(tracer.nodes.len() as u64, metrics.get(Counter::Calls));
+    assert_eq!(tracer.nodes.len(), 177);
+    assert_eq!(tracer.roots.len(), 1);
+    assert_eq!(tracer.nodes.iter().map(|node| node.depth).max(), Some(9));
+    for node in tracer.nodes.iter() {
+        assert!(node.children.is_empty() || node.children.len() == 2);
+    }
+}
+
+#[test]
+fn memo_hits_are_noted() {
+    let metrics = traced();
+    assert_eq!(fibonacci_on_the_fly_traced(&mut vec![0, 1], 10, &metrics), 55);
+    let tracer = metrics.take_tracer().unwrap();
+    let hits = tracer.nodes.iter().filter(|node| node.notes == ["memo hit"]).count();
+    // Every n from 2 to 10 is computed once, everything else is a hit.
+    assert_eq!(tracer.nodes.len() - hits, 9);
+    assert!(tracer.nodes.iter().filter(|node| !node.notes.is_empty()).all(|node| node.children.is_empty()));
+}
+
+#[test]
+fn prunes_are_noted_and_drawn() {
+    let mut prng = Prng::from_seed(1337);
+    let mut items = make_items(&mut prng, 10, 1, 10, 4, 10);
+    let allowed_weight = sum_weights(&items, true).unwrap() / 2;
+
+    let metrics = traced();
+    branch_and_bound(&mut items, allowed_weight, &metrics).unwrap();
+    let tracer = metrics.take_tracer().unwrap();
+    let pruned = tracer.nodes.iter().filter(|node| node.notes.iter().any(|note| note.starts_with("pruned"))).count();
+    assert!(pruned > 0);
+    assert_eq!(pruned as u64, metrics.get(Counter::Prunes));
+
+    let dot = tracer.to_dot(None);
+    assert!(dot.starts_with("digraph calls {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert_eq!(dot.matches(" -> ").count(), tracer.nodes.len() - 1);
+    assert_eq!(dot.matches("style=dashed").count(), pruned);
+}
+
+#[test]
+fn dot_depth_limit_summarizes_subtrees() {
+    let mut prng = Prng::from_seed(1337);
+    let mut items = make_items(&mut prng, 6, 1, 10, 4, 10);
+    let allowed_weight = sum_weights(&items, true).unwrap() / 2;
+
+    let metrics = traced();
+    exhaustive_search(&mut items, allowed_weight, &metrics).unwrap();
+    let tracer = metrics.take_tracer().unwrap();
+    assert_eq!(tracer.nodes.len(), 127);
+    let dot = tracer.to_dot(Some(2));
+    assert_eq!(dot.matches("[label=\"... 30 calls\"").count(), 4);
+    assert!(!dot.contains("do_exhaustive_search(3)"));
+}
+
+#[test]
+fn backtracking_trees_match_their_counters() {
+    let metrics = traced();
+    let mut posts = hanoi::make_posts(4);
+    hanoi::move_disks(&mut posts, 4, 0, 1, 2, false, &metrics);
+    let tracer = metrics.take_tracer().unwrap();
+    assert!(tracer.to_text(None).starts_with("move_disks(4, 0 -> 1)\n  move_disks(3, 0 -> 2)\n"));
+
+    let metrics = traced();
+    let mut board = nqueens::make_board(4);
+    assert!(nqueens::place_queens_1(&mut board, 0, 0, &metrics));
+    let tracer = metrics.take_tracer().unwrap();
+    assert_eq!(tracer.nodes.len() as u64, metrics.get(Counter::Calls));
+    assert_eq!(tracer.nodes[0].result.as_deref(), Some("true"));
+
+    let metrics = traced();
+    let mut board = knight_tour::make_board(5, 5);
+    board[0][0] = 0;
+    knight_tour::find_tour(&mut board, &knight_tour::OFFSETS, 0, 0, 1, false, &metrics);
+    let tracer = metrics.take_tracer().unwrap();
+    assert_eq!(tracer.nodes.len() as u64, metrics.get(Counter::Calls));
+    assert!(tracer.nodes.iter().any(|node| node.depth == 24));
+}
+
+#[test]
+fn node_limit_drops_later_calls() {
+    let metrics = Metrics::with_tracer(Tracer::new(10));
+    assert_eq!(fibonacci_traced(10, &metrics), 55);
+    let tracer = metrics.take_tracer().unwrap();
+    assert_eq!(tracer.nodes.len(), 10);
+    assert_eq!(tracer.num_dropped, 177 - 10);
+    assert!(tracer.nodes.iter().all(|node| node.result.is_some()));
+    assert!(tracer.to_text(None).ends_with("... 167 more calls not recorded\n"));
+}